@@ -9,7 +9,7 @@ use embassy_stm32::bind_interrupts;
 use embassy_stm32::i2c;
 use embassy_stm32::peripherals::*;
 use embassy_stm32::time;
-use embassy_time::{Duration, Timer};
+use embassy_time::{Delay, Duration, Timer};
 use lis2dtw12::interface::{I2CInterface, SlaveAddr};
 use lis2dtw12::FullScale;
 use lis2dtw12::Lis2dtw12Async;
@@ -40,7 +40,10 @@ async fn main(_spawner: Spawner) {
     let mut accelerometer = Lis2dtw12Async::new(interface);
 
     // Reset accelerometer
-    accelerometer.reset_settings_blocking().await.unwrap();
+    accelerometer
+        .reset_settings_blocking(&mut Delay, 10_000)
+        .await
+        .unwrap();
 
     accelerometer.set_full_scale(FullScale::G2).await.unwrap();
     accelerometer
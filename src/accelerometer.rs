@@ -0,0 +1,49 @@
+use ::accelerometer::error::{Error, ErrorKind};
+use ::accelerometer::vector::{F32x3, I16x3};
+use ::accelerometer::{Accelerometer, RawAccelerometer};
+
+use crate::interface::Interface;
+use crate::Lis2dtw12;
+
+/// Map an interface error to an `accelerometer` bus error
+fn bus_error<E: core::fmt::Debug>(cause: E) -> Error<E> {
+    Error::new_with_cause(ErrorKind::Bus, cause)
+}
+
+impl<I: Interface> RawAccelerometer<I16x3> for Lis2dtw12<I> {
+    type Error = I::Error;
+
+    /// Get the RAW acceleration data (right-justified, see [`Lis2dtw12::get_accel_data_raw`])
+    fn accel_raw(&mut self) -> Result<I16x3, Error<Self::Error>> {
+        let raw = self.get_accel_data_raw().map_err(bus_error)?;
+        Ok(I16x3::new(raw.x, raw.y, raw.z))
+    }
+}
+
+impl<I: Interface> Accelerometer for Lis2dtw12<I> {
+    type Error = I::Error;
+
+    /// Get the acceleration data in **g**
+    fn accel_norm(&mut self) -> Result<F32x3, Error<Self::Error>> {
+        let data = self.get_accel_data().map_err(bus_error)?;
+        Ok(F32x3::new(
+            data.x / 1000.0,
+            data.y / 1000.0,
+            data.z / 1000.0,
+        ))
+    }
+
+    /// Get the output data rate in Hz, based on the cached ODR and mode
+    ///
+    /// # Errors
+    ///
+    /// - `ErrorKind::Mode` if the device is powered down
+    fn sample_rate(&mut self) -> Result<f32, Error<Self::Error>> {
+        let rate = self.sample_format().sample_rate_hz();
+        if rate > 0.0 {
+            Ok(rate)
+        } else {
+            Err(Error::new(ErrorKind::Mode))
+        }
+    }
+}
@@ -0,0 +1,134 @@
+/// Activity detection function
+///
+/// See the [datasheet](https://www.st.com/resource/en/datasheet/lis2dtw12.pdf) (WAKE_UP_THS and WAKE_UP_DUR registers) for more info
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ActivityDetection {
+    /// Activity detection disabled
+    #[default]
+    Disabled,
+    /// Activity/Inactivity: the ODR is automatically lowered to 12.5 Hz (low-power mode) while inactive
+    ActivityInactivity,
+    /// Stationary/Motion: same detection as activity/inactivity, but without ODR change
+    StationaryMotion,
+}
+
+/// Activity detection configuration
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ActivityConfig {
+    /// Detection function
+    pub detection: ActivityDetection,
+    /// Wake-up threshold, 1 LSB = 1/64 of FS (0-63)
+    ///
+    /// Acceleration above this threshold is considered activity
+    pub wake_up_threshold: u8,
+    /// Minimum duration of the activity to wake up in seconds
+    ///
+    /// Range is 0 to 3 * 1/ODR
+    pub wake_up_duration_s: f32,
+    /// Duration of inactivity before going to sleep in seconds
+    ///
+    /// Range is 512 * 1/ODR to 15 * 512 * 1/ODR, 0 selects the minimum of 16 * 1/ODR
+    pub sleep_duration_s: f32,
+}
+
+impl Default for ActivityConfig {
+    fn default() -> Self {
+        Self {
+            detection: ActivityDetection::ActivityInactivity,
+            wake_up_threshold: 2,
+            wake_up_duration_s: 0.0,
+            sleep_duration_s: 0.0,
+        }
+    }
+}
+
+/// Activity state of the device
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ActivityState {
+    /// Device is moving (wake-up state)
+    Active,
+    /// Device is not moving (sleep state)
+    Inactive,
+}
+
+/// Transition between two activity states
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ActivityTransition {
+    /// New activity state
+    pub state: ActivityState,
+    /// Time spent in the previous state in ms
+    pub previous_duration_ms: u64,
+}
+
+/// Activity monitor
+///
+/// Tracks the transitions between [`ActivityState::Active`] and [`ActivityState::Inactive`]
+/// and the time spent in the current state.
+///
+/// Update it with [`Lis2dtw12::poll_activity`](crate::Lis2dtw12::poll_activity) or feed it the sleep state
+/// yourself (e.g. from an INT2 `SLEEP_STATE` interrupt) with [`ActivityMonitor::update`].
+/// Timestamps are supplied by the caller in ms from any monotonic clock.
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ActivityMonitor {
+    state: ActivityState,
+    since_ms: u64,
+}
+
+impl ActivityMonitor {
+    /// Create a new activity monitor
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `state`: Initial activity state
+    /// - `now_ms`: Current timestamp in ms
+    pub fn new(state: ActivityState, now_ms: u64) -> Self {
+        Self {
+            state,
+            since_ms: now_ms,
+        }
+    }
+
+    /// Update the monitor with the sleep state reported by the device
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `sleep_state`: Sleep state reported by the device (e.g. [`Status::sleep_event`](crate::Status::sleep_event))
+    /// - `now_ms`: Current timestamp in ms
+    ///
+    /// # Returns
+    ///
+    /// - The transition if the activity state changed
+    pub fn update(&mut self, sleep_state: bool, now_ms: u64) -> Option<ActivityTransition> {
+        let state = if sleep_state {
+            ActivityState::Inactive
+        } else {
+            ActivityState::Active
+        };
+        if state == self.state {
+            return None;
+        }
+
+        let previous_duration_ms = now_ms.saturating_sub(self.since_ms);
+        self.state = state;
+        self.since_ms = now_ms;
+        Some(ActivityTransition {
+            state,
+            previous_duration_ms,
+        })
+    }
+
+    /// Get the current activity state
+    pub fn state(&self) -> ActivityState {
+        self.state
+    }
+
+    /// Get the time spent in the current activity state in ms
+    pub fn time_in_state_ms(&self, now_ms: u64) -> u64 {
+        now_ms.saturating_sub(self.since_ms)
+    }
+}
@@ -0,0 +1,87 @@
+/// Data quality counters kept by the driver
+///
+/// Updated whenever samples are read through [`Lis2dtw12::read_fifo_raw`](crate::Lis2dtw12::read_fifo_raw),
+/// the samples iterator (blocking) or the sample stream (async).
+/// Get them with [`Lis2dtw12::diagnostics`](crate::Lis2dtw12::diagnostics).
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Diagnostics {
+    /// Number of samples read
+    pub samples_read: u32,
+    /// Number of detected data overruns (OVR in STATUS_DUP)
+    pub data_overruns: u32,
+    /// Number of detected FIFO overruns (FIFO_OVR in FIFO_SAMPLES)
+    pub fifo_overruns: u32,
+    /// Estimated number of lost samples
+    ///
+    /// Only counted when an overrun is detected. With a clock
+    /// (see [`Lis2dtw12::set_diagnostics_clock`](crate::Lis2dtw12::set_diagnostics_clock)) the number is
+    /// estimated from the ODR and the time elapsed since the previous read, otherwise each overrun
+    /// counts as a single lost sample.
+    pub samples_lost: u32,
+}
+
+/// Diagnostics counters and the state needed to update them
+pub(crate) struct DiagnosticsTracker {
+    pub(crate) counters: Diagnostics,
+    pub(crate) clock: Option<fn() -> u64>,
+    last_read_us: Option<u64>,
+}
+
+impl DiagnosticsTracker {
+    pub(crate) const fn new() -> Self {
+        Self {
+            counters: Diagnostics {
+                samples_read: 0,
+                data_overruns: 0,
+                fifo_overruns: 0,
+                samples_lost: 0,
+            },
+            clock: None,
+            last_read_us: None,
+        }
+    }
+
+    /// Reset the counters and the time of the previous read
+    pub(crate) fn reset(&mut self) {
+        self.counters = Diagnostics::default();
+        self.last_read_us = None;
+    }
+
+    /// Record a read of `samples` samples
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `samples`: Number of samples read
+    /// - `data_overrun`: OVR was set before the read
+    /// - `fifo_overrun`: FIFO_OVR was set before the read
+    /// - `odr_hz`: Effective output data rate
+    pub(crate) fn record(
+        &mut self,
+        samples: u32,
+        data_overrun: bool,
+        fifo_overrun: bool,
+        odr_hz: f32,
+    ) {
+        let counters = &mut self.counters;
+        counters.samples_read = counters.samples_read.saturating_add(samples);
+        counters.data_overruns = counters.data_overruns.saturating_add(data_overrun as u32);
+        counters.fifo_overruns = counters.fifo_overruns.saturating_add(fifo_overrun as u32);
+
+        let now_us = self.clock.map(|clock| clock());
+        if data_overrun || fifo_overrun {
+            let expected = match (now_us, self.last_read_us) {
+                (Some(now_us), Some(last_us)) => {
+                    let elapsed_s = now_us.saturating_sub(last_us) as f32 / 1_000_000.0;
+                    libm::floorf(elapsed_s * odr_hz) as u32
+                }
+                _ => 0,
+            };
+            let lost = expected.saturating_sub(samples).max(1);
+            counters.samples_lost = counters.samples_lost.saturating_add(lost);
+        }
+        if now_us.is_some() {
+            self.last_read_us = now_us;
+        }
+    }
+}
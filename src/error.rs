@@ -0,0 +1,28 @@
+/// Driver error
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Error<E> {
+    /// Error of the underlying interface
+    Interface(E),
+    /// The requested configuration is out of range or not supported in the current state
+    InvalidConfig,
+    /// A register contains a reserved bit pattern
+    InvalidValue(u8),
+    /// Error of the interrupt pin
+    Pin,
+    /// Output data was overwritten before it was read, samples were lost
+    Overrun,
+    /// The device did not complete the operation within the timeout
+    Timeout,
+}
+
+/// Register value that does not correspond to a valid setting (reserved bit pattern)
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct InvalidValue(pub u8);
+
+impl<E> From<InvalidValue> for Error<E> {
+    fn from(value: InvalidValue) -> Self {
+        Error::InvalidValue(value.0)
+    }
+}
@@ -0,0 +1,126 @@
+use core::f32::consts::PI;
+
+use crate::BandwidthSelection;
+
+/// Number of filter time constants after which a transient is considered settled
+const SETTLING_TIME_CONSTANTS: f32 = 5.0;
+
+/// Filter path feeding the output registers and the FIFO
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum OutputPath {
+    /// Low-pass filter path (LPF2, FDS = 0)
+    #[default]
+    LowPass,
+    /// High-pass filter path (FDS = 1)
+    HighPass,
+    /// High-pass filter path in reference mode (FDS = 1, HP_REF_MODE = 1)
+    ///
+    /// The output is the difference to the reference acceleration captured when the mode is enabled
+    HighPassReference,
+}
+
+/// Filter path feeding the 6D/4D detection
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SixDPath {
+    /// ODR/2 low-pass filtered data (LPF1, LPASS_ON6D = 0)
+    #[default]
+    OdrDiv2,
+    /// Output of the bandwidth selection low-pass filter (LPF2, LPASS_ON6D = 1)
+    LowPass,
+}
+
+/// Filter causing a settling transient
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TransientSource {
+    /// The high-pass filter removes the static acceleration, which decays over the first samples
+    HighPass,
+    /// The narrow low-pass filter delays the output after a configuration or ODR change
+    LowPass,
+}
+
+/// Settling transient of a filter configuration
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SettlingTransient {
+    /// Filter causing the transient
+    pub source: TransientSource,
+    /// Estimated number of samples to discard after applying the configuration or changing the ODR
+    pub samples: u8,
+}
+
+/// Digital filter chain configuration
+///
+/// Can be applied with [`Lis2dtw12::set_filter_config`](crate::Lis2dtw12::set_filter_config)
+///
+/// The filter chain feeds the functions of the device as follows:
+///
+/// - Output registers and FIFO: [`FilterConfig::output`] (low-pass or high-pass path,
+///   both with the cutoff selected by [`FilterConfig::bandwidth`])
+/// - Wake-up, tap and activity detection: the internal high-pass (slope) filter, not selected by
+///   [`FilterConfig::output`]. The wake-up comparator still depends on the CTRL7 wake-up data path:
+///   [`OutputPath::HighPassReference`] (HP_REF_MODE) makes it compare against the reference acceleration,
+///   and USR_OFF_ON_WU (see [`Lis2dtw12::configure_tilt_wake`](crate::Lis2dtw12::configure_tilt_wake))
+///   subtracts the user offsets from its input
+/// - 6D/4D detection: [`FilterConfig::six_d`]
+/// - Free-fall detection: ODR/2 low-pass filtered data
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FilterConfig {
+    /// Cutoff selection of the low-pass (LPF2) and high-pass filters (BW_FILT)
+    pub bandwidth: BandwidthSelection,
+    /// Filter path of the output registers and the FIFO (FDS, HP_REF_MODE)
+    pub output: OutputPath,
+    /// Filter path of the 6D/4D detection (LPASS_ON6D)
+    pub six_d: SixDPath,
+    /// Low-noise configuration (LOW_NOISE)
+    pub low_noise: bool,
+}
+
+impl FilterConfig {
+    /// Get the -3 dB cutoff frequency of the output path in Hz
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `odr_hz`: Effective output data rate (see [`OutputDataRate::as_hz`](crate::OutputDataRate::as_hz))
+    ///
+    /// # Returns
+    ///
+    /// - The low-pass cutoff for [`OutputPath::LowPass`], the high-pass cutoff otherwise,
+    ///   or `None` for the high-pass path with [`BandwidthSelection::OdrDiv2`] (not specified by the datasheet)
+    pub fn cutoff_hz(&self, odr_hz: f32) -> Option<f32> {
+        match (self.output, self.bandwidth) {
+            (OutputPath::LowPass, bandwidth) => Some(bandwidth.cutoff_hz(odr_hz)),
+            (_, BandwidthSelection::OdrDiv2) => None,
+            (_, bandwidth) => Some(bandwidth.cutoff_hz(odr_hz)),
+        }
+    }
+
+    /// Get the settling transient of the configuration, if any
+    ///
+    /// The high-pass path needs to settle whenever it is enabled (except in reference mode),
+    /// the low-pass path only with the narrow bandwidths (ODR/10 and ODR/20).
+    ///
+    /// The number of samples is estimated from a first-order response, settled after 5 time constants.
+    pub fn settling_transient(&self) -> Option<SettlingTransient> {
+        let source = match (self.output, self.bandwidth) {
+            (OutputPath::HighPass, _) => TransientSource::HighPass,
+            (OutputPath::LowPass, BandwidthSelection::OdrDiv10 | BandwidthSelection::OdrDiv20) => {
+                TransientSource::LowPass
+            }
+            _ => return None,
+        };
+        // Cutoff relative to the ODR, the high-pass cutoff for ODR/2 is not specified, assume ODR/4
+        let cutoff = match self.bandwidth.cutoff_hz(1.0) {
+            c if source == TransientSource::HighPass => c.min(0.25),
+            c => c,
+        };
+        let samples = libm::ceilf(SETTLING_TIME_CONSTANTS / (2.0 * PI * cutoff));
+        Some(SettlingTransient {
+            source,
+            samples: samples as u8,
+        })
+    }
+}
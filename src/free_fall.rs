@@ -0,0 +1,42 @@
+use crate::FreeFallThreshold;
+
+/// Standard gravity in m/s²
+const STANDARD_GRAVITY: f32 = 9.806_65;
+
+/// Free-fall detection configuration
+///
+/// Can be applied with [`Lis2dtw12::set_free_fall_config`](crate::Lis2dtw12::set_free_fall_config)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct FreeFallConfig {
+    /// Free-fall threshold @ FS = ±2 g
+    pub threshold: FreeFallThreshold,
+    /// Minimum duration of the free-fall event in ms
+    ///
+    /// Converted to 1/ODR steps (0-63) using the currently set output data rate
+    pub min_duration_ms: u16,
+}
+
+impl FreeFallConfig {
+    /// Get the minimum fall height detected with this configuration in m
+    pub fn min_height_m(&self) -> f32 {
+        free_fall_height_m(self.min_duration_ms as f32)
+    }
+}
+
+/// Estimate the free-fall height from the measured fall duration
+///
+/// The duration can be measured e.g. from the time the free-fall interrupt is
+/// active, air resistance is neglected.
+///
+/// # ARGUMENTS
+///
+/// - `duration_ms`: Measured duration of the fall in ms
+///
+/// # Returns
+///
+/// - Fall height in m (h = 1/2 * g * t²)
+pub fn free_fall_height_m(duration_ms: f32) -> f32 {
+    let t = duration_ms / 1000.0;
+    0.5 * STANDARD_GRAVITY * t * t
+}
@@ -0,0 +1,230 @@
+use crate::{AccelerationData, RawAccelerationData, Sign};
+
+/// Number of samples the FIFO can hold
+pub const FIFO_SIZE: usize = 32;
+
+/// Number of samples at the start of the FIFO used to estimate gravity
+const BASELINE_SAMPLES: usize = 4;
+
+/// Event that freezes the FIFO of the impact recorder
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ImpactTrigger {
+    /// Wake-up event (see [`Lis2dtw12::set_wake_up_threshold`](crate::Lis2dtw12::set_wake_up_threshold))
+    #[default]
+    WakeUp,
+    /// Free-fall event (see [`Lis2dtw12::set_free_fall_config`](crate::Lis2dtw12::set_free_fall_config))
+    FreeFall,
+}
+
+/// Axis of the acceleration
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Axis {
+    /// X-axis
+    X,
+    /// Y-axis
+    Y,
+    /// Z-axis
+    Z,
+}
+
+/// Impact recorder configuration
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ImpactConfig {
+    /// Event that freezes the FIFO
+    pub trigger: ImpactTrigger,
+    /// Deviation from gravity in **mg** above which a sample belongs to the impact
+    ///
+    /// Default value is 500 mg
+    pub impact_threshold: f32,
+    /// Number of samples before the trigger kept in the recording, the rest of the FIFO is filled
+    /// with samples after the trigger
+    ///
+    /// At least 5 samples are kept (the first samples are used to estimate gravity), at most 31
+    ///
+    /// Default value is 8
+    pub pre_trigger_samples: usize,
+}
+
+impl Default for ImpactConfig {
+    fn default() -> Self {
+        Self {
+            trigger: ImpactTrigger::default(),
+            impact_threshold: 500.0,
+            pre_trigger_samples: 8,
+        }
+    }
+}
+
+impl ImpactConfig {
+    /// Get the number of pre-trigger samples, limited to the supported range
+    pub(crate) fn pre_trigger(&self) -> usize {
+        self.pre_trigger_samples
+            .clamp(BASELINE_SAMPLES + 1, FIFO_SIZE - 1)
+    }
+}
+
+/// Report of a recorded impact
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ImpactReport {
+    /// Peak acceleration (magnitude, gravity removed) in **g**
+    pub peak_g: f32,
+    /// Acceleration at the peak (gravity removed) in **mg**
+    pub peak: AccelerationData,
+    /// Index of the peak sample in the recorded samples
+    pub peak_index: usize,
+    /// Duration of the impact (samples deviating more than the threshold around the peak) in ms
+    pub duration_ms: f32,
+    /// Direction of the impact (unit vector)
+    pub direction: AccelerationData,
+    /// Axis with the largest share of the impact
+    pub axis: Axis,
+    /// Sign of the impact on the dominant axis
+    pub sign: Sign,
+}
+
+impl ImpactReport {
+    /// Analyze recorded samples
+    ///
+    /// Gravity is estimated from the first samples, which are recorded before the trigger event.
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `samples`: Recorded samples in **mg**
+    /// - `odr_hz`: Output data rate the samples were recorded with
+    /// - `impact_threshold`: Deviation from gravity in **mg** above which a sample belongs to the impact
+    ///
+    /// # Returns
+    ///
+    /// - The report, or `None` if there are not enough samples
+    pub fn analyze(
+        samples: &[AccelerationData],
+        odr_hz: f32,
+        impact_threshold: f32,
+    ) -> Option<Self> {
+        if samples.len() <= BASELINE_SAMPLES || odr_hz == 0.0 {
+            return None;
+        }
+
+        let baseline =
+            samples[..BASELINE_SAMPLES]
+                .iter()
+                .fold(AccelerationData::default(), |acc, s| AccelerationData {
+                    x: acc.x + s.x / BASELINE_SAMPLES as f32,
+                    y: acc.y + s.y / BASELINE_SAMPLES as f32,
+                    z: acc.z + s.z / BASELINE_SAMPLES as f32,
+                });
+        let dynamic = |s: &AccelerationData| AccelerationData {
+            x: s.x - baseline.x,
+            y: s.y - baseline.y,
+            z: s.z - baseline.z,
+        };
+
+        let (peak_index, peak, peak_mg) = samples
+            .iter()
+            .enumerate()
+            .map(|(i, s)| {
+                let d = dynamic(s);
+                (i, d, d.magnitude())
+            })
+            .fold(
+                (0, baseline, -1.0),
+                |max, cur| if cur.2 > max.2 { cur } else { max },
+            );
+
+        let above = |s: &AccelerationData| dynamic(s).magnitude() > impact_threshold;
+        let before = samples[..peak_index]
+            .iter()
+            .rev()
+            .take_while(|s| above(s))
+            .count();
+        let after = samples[peak_index..]
+            .iter()
+            .take_while(|s| above(s))
+            .count();
+
+        let direction = if peak_mg > 0.0 {
+            AccelerationData {
+                x: peak.x / peak_mg,
+                y: peak.y / peak_mg,
+                z: peak.z / peak_mg,
+            }
+        } else {
+            AccelerationData::default()
+        };
+        let (axis, value) = [(Axis::X, peak.x), (Axis::Y, peak.y), (Axis::Z, peak.z)]
+            .into_iter()
+            .fold((Axis::X, 0.0f32), |max, cur| {
+                if libm::fabsf(cur.1) > libm::fabsf(max.1) {
+                    cur
+                } else {
+                    max
+                }
+            });
+
+        Some(Self {
+            peak_g: peak_mg / 1000.0,
+            peak,
+            peak_index,
+            duration_ms: (before + after) as f32 * 1000.0 / odr_hz,
+            direction,
+            axis,
+            sign: if value < 0.0 {
+                Sign::Negative
+            } else {
+                Sign::Positive
+            },
+        })
+    }
+}
+
+/// Impact recorder
+///
+/// Uses the FIFO in [`FifoMode::ContinuousToFifo`](crate::FifoMode::ContinuousToFifo): the FIFO continuously
+/// records samples until the trigger event occurs and then stops when it is full. As the FIFO is usually
+/// already full at the trigger, the oldest samples are discarded when the trigger is detected, keeping
+/// [`ImpactConfig::pre_trigger_samples`] samples before the trigger, and the FIFO is filled up again
+/// with samples after the trigger.
+///
+/// Arm it with [`Lis2dtw12::arm_impact_recorder`](crate::Lis2dtw12::arm_impact_recorder) and poll it with
+/// [`Lis2dtw12::poll_impact_recorder`](crate::Lis2dtw12::poll_impact_recorder).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ImpactRecorder {
+    pub(crate) config: ImpactConfig,
+    pub(crate) samples: [RawAccelerationData; FIFO_SIZE],
+    pub(crate) len: usize,
+    pub(crate) armed: bool,
+    pub(crate) triggered: bool,
+}
+
+impl ImpactRecorder {
+    /// Create a new impact recorder
+    pub fn new(config: ImpactConfig) -> Self {
+        Self {
+            config,
+            samples: [RawAccelerationData::default(); FIFO_SIZE],
+            len: 0,
+            armed: false,
+            triggered: false,
+        }
+    }
+
+    /// Get the configuration of the impact recorder
+    pub fn config(&self) -> &ImpactConfig {
+        &self.config
+    }
+
+    /// Get the RAW samples of the last recorded impact
+    pub fn samples(&self) -> &[RawAccelerationData] {
+        &self.samples[..self.len]
+    }
+
+    /// Check if the recorder is armed and waiting for an impact
+    pub fn is_armed(&self) -> bool {
+        self.armed
+    }
+}
@@ -0,0 +1,168 @@
+use crate::registers::*;
+use crate::AllSources;
+
+/// Interrupt pin
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InterruptPin {
+    /// INT1 pad
+    Int1,
+    /// INT2 pad
+    Int2,
+}
+
+/// Interrupt source that can be routed to an interrupt pin
+///
+/// Not every source is available on both pins: the embedded functions (6D, tap, wake-up and free-fall)
+/// are only available on INT1, the sleep, boot, temperature and overrun signals only on INT2.
+/// INT2-only sources can still be routed to INT1 using INT2_ON_INT1, as long as no other source uses INT2.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum InterruptSource {
+    /// 6D recognition (INT1)
+    SixD,
+    /// Single-tap recognition (INT1)
+    SingleTap,
+    /// Wake-up recognition (INT1)
+    WakeUp,
+    /// Free-fall recognition (INT1)
+    FreeFall,
+    /// Double-tap recognition (INT1)
+    DoubleTap,
+    /// FIFO full recognition (INT1/INT2)
+    FifoFull,
+    /// FIFO threshold (INT1/INT2)
+    FifoThreshold,
+    /// Data-ready (INT1/INT2)
+    DataReady,
+    /// Sleep state (INT2)
+    SleepState,
+    /// Sleep change status (INT2)
+    SleepChange,
+    /// Boot state (INT2)
+    Boot,
+    /// Temperature data-ready (INT2)
+    TemperatureDataReady,
+    /// FIFO overrun (INT2)
+    FifoOverrun,
+}
+
+impl InterruptSource {
+    /// Get the bit of the source in CTRL4_INT1_PAD_CTRL, if available on INT1
+    pub(crate) fn int1_bit(self) -> Option<u8> {
+        match self {
+            InterruptSource::SixD => Some(INT1_6D),
+            InterruptSource::SingleTap => Some(INT1_SINGLE_TAP),
+            InterruptSource::WakeUp => Some(INT1_WU),
+            InterruptSource::FreeFall => Some(INT1_FF),
+            InterruptSource::DoubleTap => Some(INT1_TAP),
+            InterruptSource::FifoFull => Some(INT1_DIFF5),
+            InterruptSource::FifoThreshold => Some(INT1_FTH),
+            InterruptSource::DataReady => Some(INT1_DRDY),
+            _ => None,
+        }
+    }
+
+    /// Get the bit of the source in CTRL5_INT2_PAD_CTRL, if available on INT2
+    pub(crate) fn int2_bit(self) -> Option<u8> {
+        match self {
+            InterruptSource::SleepState => Some(INT2_SLEEP_STATE),
+            InterruptSource::SleepChange => Some(INT2_SLEEP_CHG),
+            InterruptSource::Boot => Some(INT2_BOOT),
+            InterruptSource::TemperatureDataReady => Some(INT2_DRDY_T),
+            InterruptSource::FifoOverrun => Some(INT2_OVR),
+            InterruptSource::FifoFull => Some(INT2_DIFF5),
+            InterruptSource::FifoThreshold => Some(INT2_FTH),
+            InterruptSource::DataReady => Some(INT2_DRDY),
+            _ => None,
+        }
+    }
+
+    /// Check if the source is an embedded function, which needs INTERRUPTS_ENABLE
+    pub(crate) fn is_function(self) -> bool {
+        matches!(
+            self,
+            InterruptSource::SixD
+                | InterruptSource::SingleTap
+                | InterruptSource::WakeUp
+                | InterruptSource::FreeFall
+                | InterruptSource::DoubleTap
+                | InterruptSource::SleepState
+                | InterruptSource::SleepChange
+        )
+    }
+}
+
+/// Set of pending (latched) interrupt events
+///
+/// Returned by [`Lis2dtw12::acknowledge`](crate::Lis2dtw12::acknowledge), also used to select the events
+/// to clear with [`Lis2dtw12::acknowledge_only`](crate::Lis2dtw12::acknowledge_only)
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PendingEvents {
+    /// Wake-up event (cleared by reading WAKE_UP_SRC)
+    pub wake_up: bool,
+    /// Free-fall event (cleared by reading WAKE_UP_SRC)
+    pub free_fall: bool,
+    /// Sleep change event (cleared by reading WAKE_UP_SRC)
+    pub sleep_change: bool,
+    /// Single-tap event (cleared by reading TAP_SRC)
+    pub single_tap: bool,
+    /// Double-tap event (cleared by reading TAP_SRC)
+    pub double_tap: bool,
+    /// 6D event (cleared by reading SIXD_SRC)
+    pub six_d: bool,
+}
+
+impl PendingEvents {
+    /// All events
+    pub const ALL: Self = Self {
+        wake_up: true,
+        free_fall: true,
+        sleep_change: true,
+        single_tap: true,
+        double_tap: true,
+        six_d: true,
+    };
+
+    /// Check if no event is set
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Get the events set in `self` but not in `other`
+    pub fn difference(&self, other: &Self) -> Self {
+        Self {
+            wake_up: self.wake_up && !other.wake_up,
+            free_fall: self.free_fall && !other.free_fall,
+            sleep_change: self.sleep_change && !other.sleep_change,
+            single_tap: self.single_tap && !other.single_tap,
+            double_tap: self.double_tap && !other.double_tap,
+            six_d: self.six_d && !other.six_d,
+        }
+    }
+
+    /// Check if one of the events cleared by reading WAKE_UP_SRC is set
+    pub(crate) fn wake_up_src(&self) -> bool {
+        self.wake_up || self.free_fall || self.sleep_change
+    }
+
+    /// Check if one of the events cleared by reading TAP_SRC is set
+    pub(crate) fn tap_src(&self) -> bool {
+        self.single_tap || self.double_tap
+    }
+}
+
+impl From<AllSources> for PendingEvents {
+    fn from(value: AllSources) -> Self {
+        let all = value.all_interrupt_sources;
+        Self {
+            wake_up: value.wake_up_source.wake_up_event || all.wake_up_interrupt,
+            free_fall: value.wake_up_source.free_fall_event || all.free_fall_interrupt,
+            sleep_change: value.wake_up_source.sleep_event || all.sleep_change_interrupt,
+            single_tap: value.tap_source.single_tap_event || all.single_tap_interrupt,
+            double_tap: value.tap_source.double_tap_event || all.double_tap_interrupt,
+            six_d: value.six_d_source.position_change_event || all.six_d_interrupt,
+        }
+    }
+}
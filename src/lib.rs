@@ -2,6 +2,7 @@
 //! A platform agnostic driver to interface with the LIS2DTW12 (3-axis accelerometer + temperature sensor).
 //! The driver uses the `embedded-hal` traits and supports interfaces with I2C and SPI.
 //! The driver supports async and blocking modes, selectable with the `async` and `blocking` features.
+//! With the `accelerometer` feature the blocking driver implements the traits of the `accelerometer` crate.
 //!
 
 #![deny(missing_docs)]
@@ -11,25 +12,83 @@
 
 mod fmt;
 
+#[cfg(all(feature = "accelerometer", feature = "blocking"))]
+mod accelerometer;
+mod activity;
+mod diagnostics;
+mod error;
+mod filter;
+mod free_fall;
 mod i2c;
+mod impact;
+mod interrupt;
+mod poller;
+mod power;
+mod reference;
+mod register_cache;
 mod register_data;
 mod register_settings;
 mod registers;
+#[cfg(feature = "blocking")]
+mod samples;
+mod self_test;
 mod spi;
+#[cfg(feature = "async")]
+mod stream;
+mod tamper;
+mod tilt;
 
 /// Interface module, contains the `Interface` trait and re-exports the `i2c` and `spi` modules
 pub mod interface;
+/// Software pedometer working on FIFO batches of acceleration data
+pub mod pedometer;
+/// Typed register access for bitfields not covered by the high-level API
+pub mod register_access;
+/// Fixed-size FFT spectrum analysis of acceleration data
+#[cfg(feature = "spectrum")]
+pub mod spectrum;
+/// Vibration velocity and displacement integration with ISO 10816 severity zones
+pub mod velocity;
+/// Vibration metrics over windows of acceleration data
+pub mod vibration;
 
 #[cfg(all(feature = "blocking", feature = "async"))]
 compile_error!("feature \"blocking\" and feature \"async\" cannot be enabled at the same time");
 #[cfg(not(any(feature = "blocking", feature = "async")))]
 compile_error!("either feature \"blocking\" or feature \"async\" must be enabled");
 
+use diagnostics::DiagnosticsTracker;
+#[cfg(feature = "blocking")]
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs;
 use interface::Interface;
+use register_access::{RegisterValue, WritableRegister};
+use register_cache::RegisterCache;
 use registers::*;
 
+pub use activity::*;
+pub use diagnostics::*;
+pub use error::*;
+pub use filter::*;
+pub use free_fall::*;
+pub use impact::*;
+pub use interrupt::*;
+pub use poller::*;
+pub use power::*;
+pub use reference::*;
 pub use register_data::*;
 pub use register_settings::*;
+#[cfg(feature = "blocking")]
+pub use samples::*;
+pub use self_test::*;
+#[cfg(feature = "async")]
+pub use stream::*;
+pub use tamper::*;
+pub use tilt::*;
+
+/// Poll interval in µs of the status polling methods (reset, boot and data-ready)
+const POLL_INTERVAL_US: u32 = 100;
 
 /// LIS2DTW12 driver
 #[maybe_async_cfg::maybe(sync(feature = "blocking", keep_self), async(feature = "async"))]
@@ -37,6 +96,10 @@ pub struct Lis2dtw12<I> {
     interface: I,
     mode: Mode,
     fullscale: FullScale,
+    odr: OutputDataRate,
+    bandwidth: BandwidthSelection,
+    cache: RegisterCache,
+    diagnostics: DiagnosticsTracker,
 }
 
 /// LIS2DTW12 driver
@@ -48,14 +111,131 @@ impl<I: Interface> Lis2dtw12<I> {
             interface,
             mode: Mode::default(),
             fullscale: FullScale::default(),
+            // CTRL1 resets to power-down
+            odr: OutputDataRate::PowerDown,
+            bandwidth: BandwidthSelection::default(),
+            cache: RegisterCache::new(),
+            diagnostics: DiagnosticsTracker::new(),
         }
     }
 
+    /// Create a new `LIS2DTW12` driver from a given interface, reading the cached settings from the device
+    ///
+    /// Use this instead of [`Self::new`] to resume an already configured device (e.g. after
+    /// the MCU woke up from deep sleep), no settings are written, so the FIFO contents and the
+    /// configured interrupts are preserved.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if CTRL1 or CTRL6 contain a reserved bit pattern
+    pub async fn attach(interface: I) -> Result<Self, Error<I::Error>> {
+        let mut lis2dtw12 = Self::new(interface);
+        let mut ctrl = [0; 6];
+        lis2dtw12
+            .read_regs(Register::CTRL1, &mut ctrl)
+            .await
+            .map_err(Error::Interface)?;
+        let (ctrl1, ctrl6) = (ctrl[0], ctrl[5]);
+
+        lis2dtw12.odr = OutputDataRate::try_from((ctrl1 & ODR_MASK) >> ODR_SHIFT)?;
+        lis2dtw12.mode = Mode::try_from((ctrl1 & (MODE_MASK | LP_MODE_MASK)) >> LP_MODE_SHIFT)?;
+        lis2dtw12.bandwidth =
+            BandwidthSelection::try_from((ctrl6 & BW_FILT_MASK) >> BW_FILT_SHIFT)?;
+        lis2dtw12.fullscale = FullScale::try_from((ctrl6 & FS_MASK) >> FS_SHIFT)?;
+        Ok(lis2dtw12)
+    }
+
     /// Destroy the driver instance returning the interface instance
     pub fn destroy(self) -> I {
         self.interface
     }
 
+    /// Get the sample format (mode, full-scale and output data rate) cached by the driver
+    pub fn sample_format(&self) -> SampleFormat {
+        SampleFormat {
+            mode: self.mode,
+            full_scale: self.fullscale,
+            odr: self.odr,
+            bandwidth: self.bandwidth,
+        }
+    }
+
+    /// Enable or disable the register cache
+    ///
+    /// The cache keeps a copy of the control registers written by the driver, so that
+    /// setters only need a single write instead of a read-modify-write, and writes that
+    /// don't change the register value are skipped.
+    ///
+    /// Status and output registers are never cached.
+    /// Enabling or disabling the cache invalidates it, see [`Self::refresh_register_cache`].
+    ///
+    /// # NOTE
+    ///
+    /// The cache assumes the driver has exclusive access to the device.
+    /// It is invalidated on a soft reset or boot issued through the driver,
+    /// call [`Self::invalidate_register_cache`] if the device is reset or power cycled otherwise.
+    ///
+    /// Disabled by default
+    pub fn enable_register_cache(&mut self, enable: bool) {
+        self.cache.set_enabled(enable);
+    }
+
+    /// Check if the register cache is enabled
+    pub fn is_register_cache_enabled(&self) -> bool {
+        self.cache.is_enabled()
+    }
+
+    /// Invalidate the register cache
+    ///
+    /// Each control register is read from the device again before its next modification
+    pub fn invalidate_register_cache(&mut self) {
+        self.cache.invalidate();
+    }
+
+    /// Read all control registers from the device into the register cache
+    ///
+    /// # NOTE
+    ///
+    /// Has no effect if the register cache is disabled
+    pub async fn refresh_register_cache(&mut self) -> Result<(), I::Error> {
+        if !self.cache.is_enabled() {
+            return Ok(());
+        }
+        for (first, len) in register_cache::BLOCKS {
+            let mut buffer = [0; register_cache::MAX_BLOCK_LEN];
+            self.read_regs(first, &mut buffer[..len]).await?;
+            self.cache.fill(first, &buffer[..len]);
+        }
+        Ok(())
+    }
+
+    /// Get the data quality counters (overruns and lost samples)
+    pub fn diagnostics(&self) -> Diagnostics {
+        self.diagnostics.counters
+    }
+
+    /// Reset the data quality counters
+    pub fn reset_diagnostics(&mut self) {
+        self.diagnostics.reset();
+    }
+
+    /// Set the clock used to estimate the number of lost samples
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `clock`: Function returning a monotonic timestamp in µs, or `None` to count each overrun
+    ///   as a single lost sample
+    pub fn set_diagnostics_clock(&mut self, clock: Option<fn() -> u64>) {
+        self.diagnostics.clock = clock;
+    }
+
+    /// Record a read of `samples` samples in the data quality counters
+    pub(crate) fn record_samples(&mut self, samples: u32, data_overrun: bool, fifo_overrun: bool) {
+        let odr_hz = self.odr.as_hz(self.mode);
+        self.diagnostics
+            .record(samples, data_overrun, fifo_overrun, odr_hz);
+    }
+
     /// Read the WHO_AM_I register
     pub async fn get_device_id(&mut self) -> Result<u8, I::Error> {
         self.read_reg(Register::WHO_AM_I).await
@@ -77,14 +257,37 @@ impl<I: Interface> Lis2dtw12<I> {
     }
 
     /// Set the Output Data Rate
+    ///
+    /// See [`Self::set_power_config`] to set a validated combination of mode and output data rate
     pub async fn set_output_data_rate(&mut self, odr: OutputDataRate) -> Result<(), I::Error> {
         self.modify_reg(Register::CTRL1, |v| {
             v & !ODR_MASK | (odr as u8) << ODR_SHIFT
         })
-        .await
+        .await?;
+        self.odr = odr;
+        Ok(())
+    }
+
+    /// Get the Output Data Rate
+    ///
+    /// Also updates the setting cached by the driver
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_output_data_rate(&mut self) -> Result<OutputDataRate, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::CTRL1)
+            .await
+            .map_err(Error::Interface)?;
+        let value = OutputDataRate::try_from((v & ODR_MASK) >> ODR_SHIFT)?;
+        self.odr = value;
+        Ok(value)
     }
 
     /// Set the Mode
+    ///
+    /// See [`Self::set_power_config`] to set a validated combination of mode and output data rate
     pub async fn set_mode(&mut self, mode: Mode) -> Result<(), I::Error> {
         self.modify_reg(Register::CTRL1, |v| {
             v & !(MODE_MASK | LP_MODE_MASK) | (mode as u8)
@@ -94,20 +297,94 @@ impl<I: Interface> Lis2dtw12<I> {
         Ok(())
     }
 
+    /// Get the Mode
+    ///
+    /// Also updates the setting cached by the driver
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_mode(&mut self) -> Result<Mode, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::CTRL1)
+            .await
+            .map_err(Error::Interface)?;
+        let value = Mode::try_from((v & (MODE_MASK | LP_MODE_MASK)) >> LP_MODE_SHIFT)?;
+        self.mode = value;
+        Ok(value)
+    }
+
+    /// Set the power configuration (mode, output data rate and low-noise setting)
+    ///
+    /// Mode and output data rate are written together in a single write of CTRL1. If the low-noise setting
+    /// changes, the device is powered down before CTRL6 is written, so no sample is taken with a mix of
+    /// the previous and the new settings.
+    ///
+    /// # Returns
+    ///
+    /// - The effective output data rate in Hz (see [`PowerConfig::validate`])
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the combination is not supported, see [`PowerConfig::validate`] for the reason
+    pub async fn set_power_config(&mut self, config: PowerConfig) -> Result<f32, Error<I::Error>> {
+        let odr_hz = config.validate().map_err(|_| Error::InvalidConfig)?;
+
+        let ctrl1 = self
+            .read_reg_cached(Register::CTRL1)
+            .await
+            .map_err(Error::Interface)?;
+        let ctrl6 = self
+            .read_reg_cached(Register::CTRL6)
+            .await
+            .map_err(Error::Interface)?;
+        let low_noise = if config.low_noise { LOW_NOISE } else { 0 };
+        if ctrl6 & LOW_NOISE != low_noise {
+            // Power down while the low-noise setting changes
+            self.write_reg(Register::CTRL1, ctrl1 & !ODR_MASK)
+                .await
+                .map_err(Error::Interface)?;
+            self.write_reg(Register::CTRL6, ctrl6 & !LOW_NOISE | low_noise)
+                .await
+                .map_err(Error::Interface)?;
+        }
+        self.write_reg(
+            Register::CTRL1,
+            ctrl1 & !(ODR_MASK | MODE_MASK | LP_MODE_MASK)
+                | (config.odr as u8) << ODR_SHIFT
+                | (config.mode as u8),
+        )
+        .await
+        .map_err(Error::Interface)?;
+        self.mode = config.mode;
+        self.odr = config.odr;
+        Ok(odr_hz)
+    }
+
     /// Reset all settings (CTRL registers to default)
     ///
     /// # NOTE
     ///
-    /// This will block until the reset is complete
+    /// This will wait until the reset is complete, polling the reset status every 100 µs
     ///
-    /// Consider using [`Self::reset_settings`] and polling the reset status using [`Self::get_reset_complete`]
+    /// # ARGUMENTS
     ///
-    pub async fn reset_settings_blocking(&mut self) -> Result<(), I::Error> {
-        self.reg_set_bits(Register::CTRL2, SOFT_RESET).await?;
-        self.mode = Mode::default();
-        // TODO: Make this smarter instead of just blocking
-        while self.read_reg(Register::CTRL2).await? & SOFT_RESET != 0 {}
-
+    /// - `delay`: Delay used between the polls
+    /// - `timeout_us`: Maximum time to wait in µs
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Timeout`] if the reset did not complete within the timeout
+    pub async fn reset_settings_blocking<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_us: u32,
+    ) -> Result<(), Error<I::Error>> {
+        self.reset_settings().await.map_err(Error::Interface)?;
+        self.wait_for(Register::CTRL2, delay, POLL_INTERVAL_US, timeout_us, |v| {
+            v & SOFT_RESET == 0
+        })
+        .await?;
         Ok(())
     }
 
@@ -123,6 +400,9 @@ impl<I: Interface> Lis2dtw12<I> {
     pub async fn reset_settings(&mut self) -> Result<(), I::Error> {
         self.reg_set_bits(Register::CTRL2, SOFT_RESET).await?;
         self.mode = Mode::default();
+        self.fullscale = FullScale::default();
+        self.odr = OutputDataRate::PowerDown;
+        self.bandwidth = BandwidthSelection::default();
         Ok(())
     }
 
@@ -131,6 +411,165 @@ impl<I: Interface> Lis2dtw12<I> {
         Ok(self.read_reg(Register::CTRL2).await? & SOFT_RESET == 0)
     }
 
+    /// Reboot the memory content (reload the trimming parameters)
+    ///
+    /// Waits until the boot procedure is complete, polling the boot status every 100 µs
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `delay`: Delay used between the polls
+    /// - `timeout_us`: Maximum time to wait in µs (the boot procedure takes about 20 ms)
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Timeout`] if the boot procedure did not complete within the timeout
+    pub async fn reboot<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_us: u32,
+    ) -> Result<(), Error<I::Error>> {
+        self.reg_set_bits(Register::CTRL2, BOOT)
+            .await
+            .map_err(Error::Interface)?;
+        self.wait_for(Register::CTRL2, delay, POLL_INTERVAL_US, timeout_us, |v| {
+            v & BOOT == 0
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Wait until new acceleration data is available
+    ///
+    /// Polls the data-ready status every 100 µs
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `delay`: Delay used between the polls
+    /// - `timeout_us`: Maximum time to wait in µs
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Timeout`] if no data was available within the timeout
+    pub async fn wait_data_ready<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_us: u32,
+    ) -> Result<(), Error<I::Error>> {
+        self.wait_for(Register::STATUS, delay, POLL_INTERVAL_US, timeout_us, |v| {
+            v & DRDY != 0
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Trigger a single data conversion and read the result
+    ///
+    /// A sample still pending from a previous conversion is read and discarded first, so the data-ready
+    /// status is cleared. The conversion is then triggered with SLP_MODE_1 (SLP_MODE_SEL is set),
+    /// the result is read as soon as it is available.
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `delay`: Delay used between the polls of the data-ready status
+    /// - `timeout_us`: Maximum time to wait for the conversion in µs
+    ///
+    /// # Returns
+    ///
+    /// - `AccelerationData` struct containing the acceleration data in **mg**
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the device is not in a single data conversion mode
+    /// - [`Error::Timeout`] if the conversion did not complete within the timeout
+    pub async fn read_single_conversion<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_us: u32,
+    ) -> Result<AccelerationData, Error<I::Error>> {
+        if !matches!(
+            self.mode,
+            Mode::SingleConversionLowPower1
+                | Mode::SingleConversionLowPower2
+                | Mode::SingleConversionLowPower3
+                | Mode::SingleConversionLowPower4
+        ) {
+            return Err(Error::InvalidConfig);
+        }
+        // Discard a stale sample, reading the output registers clears DRDY
+        let status = self
+            .read_reg(Register::STATUS)
+            .await
+            .map_err(Error::Interface)?;
+        if status & DRDY != 0 {
+            self.get_accel_data_raw().await.map_err(Error::Interface)?;
+        }
+        self.reg_set_bits(Register::CTRL3, SLP_MODE_SEL | SLP_MODE_1)
+            .await
+            .map_err(Error::Interface)?;
+        self.wait_data_ready(delay, timeout_us).await?;
+        self.get_accel_data().await.map_err(Error::Interface)
+    }
+
+    /// Set the self-test mode
+    pub async fn set_self_test(&mut self, self_test: SelfTest) -> Result<(), I::Error> {
+        self.modify_reg(Register::CTRL3, |v| {
+            v & !ST_MASK | (self_test as u8) << ST_SHIFT
+        })
+        .await
+    }
+
+    /// Run the self-test procedure of the datasheet
+    ///
+    /// Configures 50 Hz high-performance mode @ FS = ±4 g, averages 5 samples with self-test disabled and
+    /// 5 samples with positive self-test, each after a settling time of 100 ms.
+    /// The previous configuration (CTRL1, CTRL3 and CTRL6) is restored afterwards.
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `delay`: Delay used for the settling time and between the polls of the data-ready status
+    /// - `timeout_us`: Maximum time to wait for each sample in µs
+    ///
+    /// # Returns
+    ///
+    /// - The averaged outputs, check [`SelfTestResult::passed`] for the result
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::Timeout`] if a sample was not available within the timeout
+    pub async fn run_self_test<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_us: u32,
+    ) -> Result<SelfTestResult, Error<I::Error>> {
+        let mut saved = [0; 6];
+        self.read_regs(Register::CTRL1, &mut saved)
+            .await
+            .map_err(Error::Interface)?;
+        let (mode, odr, fullscale, bandwidth) =
+            (self.mode, self.odr, self.fullscale, self.bandwidth);
+
+        let result = self.self_test_measure(delay, timeout_us).await;
+
+        // Power down before restoring the previous configuration
+        self.write_reg(Register::CTRL1, 0)
+            .await
+            .map_err(Error::Interface)?;
+        self.write_reg(Register::CTRL3, saved[2])
+            .await
+            .map_err(Error::Interface)?;
+        self.write_reg(Register::CTRL6, saved[5])
+            .await
+            .map_err(Error::Interface)?;
+        self.write_reg(Register::CTRL1, saved[0])
+            .await
+            .map_err(Error::Interface)?;
+        self.mode = mode;
+        self.odr = odr;
+        self.fullscale = fullscale;
+        self.bandwidth = bandwidth;
+        result
+    }
+
     /// (Dis-)connect CS pull-up (only relevant when using SPI interface)
     pub async fn disconnect_cs_pull_up(&mut self, disconnect: bool) -> Result<(), I::Error> {
         if disconnect {
@@ -160,7 +599,26 @@ impl<I: Interface> Lis2dtw12<I> {
         self.modify_reg(Register::CTRL6, |v| {
             v & !BW_FILT_MASK | (bandwidth as u8) << BW_FILT_SHIFT
         })
-        .await
+        .await?;
+        self.bandwidth = bandwidth;
+        Ok(())
+    }
+
+    /// Get the bandwidth selection
+    ///
+    /// Also updates the setting cached by the driver
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_bandwidth(&mut self) -> Result<BandwidthSelection, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::CTRL6)
+            .await
+            .map_err(Error::Interface)?;
+        let value = BandwidthSelection::try_from((v & BW_FILT_MASK) >> BW_FILT_SHIFT)?;
+        self.bandwidth = value;
+        Ok(value)
     }
 
     /// Set the full-scale selection
@@ -173,18 +631,217 @@ impl<I: Interface> Lis2dtw12<I> {
         Ok(())
     }
 
+    /// Get the full-scale selection
+    ///
+    /// Also updates the setting cached by the driver
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_full_scale(&mut self) -> Result<FullScale, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::CTRL6)
+            .await
+            .map_err(Error::Interface)?;
+        let value = FullScale::try_from((v & FS_MASK) >> FS_SHIFT)?;
+        self.fullscale = value;
+        Ok(value)
+    }
+
     /// Set configuration for INT1 pad
+    ///
+    /// # NOTE
+    ///
+    /// Overwrites all sources routed to INT1, use [`Self::route`] to route a single source
     pub async fn configure_int1_pad(&mut self, config: Int1PadConfig) -> Result<(), I::Error> {
         self.write_reg(Register::CTRL4_INT1_PAD_CTRL, config.into())
             .await
     }
 
     /// Set configuration for INT2 pad
+    ///
+    /// # NOTE
+    ///
+    /// Overwrites all sources routed to INT2, use [`Self::route`] to route a single source
     pub async fn configure_int2_pad(&mut self, config: Int2PadConfig) -> Result<(), I::Error> {
         self.write_reg(Register::CTRL5_INT2_PAD_CTRL, config.into())
             .await
     }
 
+    /// Select the sleep signal routed to the INT2 pad
+    ///
+    /// Only the sleep bits of the INT2 pad configuration are changed,
+    /// other sources routed to INT2 are kept.
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `signal`: Sleep state (level), sleep change (edge) or no sleep signal
+    ///
+    /// # NOTE
+    ///
+    /// Only works when also enabling interrupts [`Self::enable_interrupts`]
+    pub async fn set_int2_sleep_signal(&mut self, signal: SleepSignal) -> Result<(), I::Error> {
+        let bits = match signal {
+            SleepSignal::Disabled => 0,
+            SleepSignal::State => INT2_SLEEP_STATE,
+            SleepSignal::Change => INT2_SLEEP_CHG,
+        };
+        self.modify_reg(Register::CTRL5_INT2_PAD_CTRL, |v| {
+            v & !(INT2_SLEEP_STATE | INT2_SLEEP_CHG) | bits
+        })
+        .await
+    }
+
+    /// Route an interrupt source to an interrupt pin
+    ///
+    /// Other sources routed to the pads are kept. INT2-only sources are routed to INT1 with INT2_ON_INT1,
+    /// and INTERRUPTS_ENABLE is set for the embedded functions (6D, tap, wake-up, free-fall and sleep).
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the pin can't carry the source (e.g. taps on INT2), or if changing
+    ///   INT2_ON_INT1 would move other sources already routed through the INT2 pad configuration
+    pub async fn route(
+        &mut self,
+        source: InterruptSource,
+        pin: InterruptPin,
+    ) -> Result<(), Error<I::Error>> {
+        let ctrl5 = self
+            .read_reg_cached(Register::CTRL5_INT2_PAD_CTRL)
+            .await
+            .map_err(Error::Interface)?;
+        let ctrl7 = self
+            .read_reg_cached(Register::CTRL7)
+            .await
+            .map_err(Error::Interface)?;
+        let int2_on_int1 = ctrl7 & INT2_ON_INT1 != 0;
+        let (reg, bit, new_int2_on_int1) = match (pin, source.int1_bit(), source.int2_bit()) {
+            (InterruptPin::Int1, Some(bit), _) => {
+                (Register::CTRL4_INT1_PAD_CTRL, bit, int2_on_int1)
+            }
+            (InterruptPin::Int2, _, Some(bit)) => (Register::CTRL5_INT2_PAD_CTRL, bit, false),
+            (InterruptPin::Int1, None, Some(bit)) => (Register::CTRL5_INT2_PAD_CTRL, bit, true),
+            _ => return Err(Error::InvalidConfig),
+        };
+        if new_int2_on_int1 != int2_on_int1 && ctrl5 & !bit != 0 {
+            return Err(Error::InvalidConfig);
+        }
+
+        let enable = if source.is_function() {
+            INTERRUPTS_ENABLE
+        } else {
+            0
+        };
+        let int2_on_int1 = if new_int2_on_int1 { INT2_ON_INT1 } else { 0 };
+        self.modify_reg(Register::CTRL7, |v| {
+            v & !INT2_ON_INT1 | int2_on_int1 | enable
+        })
+        .await
+        .map_err(Error::Interface)?;
+        self.reg_set_bits(reg, bit).await.map_err(Error::Interface)
+    }
+
+    /// Remove an interrupt source from an interrupt pin
+    ///
+    /// Other sources routed to the pads are kept. INT2_ON_INT1 is cleared once no source is routed
+    /// through the INT2 pad configuration anymore. Does nothing if the source is not routed to the pin.
+    ///
+    /// # NOTE
+    ///
+    /// INTERRUPTS_ENABLE is kept, as the event status bits depend on it too.
+    /// Use [`Self::enable_interrupts`] to disable it.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the pin can't carry the source
+    pub async fn unroute(
+        &mut self,
+        source: InterruptSource,
+        pin: InterruptPin,
+    ) -> Result<(), Error<I::Error>> {
+        let ctrl5 = self
+            .read_reg_cached(Register::CTRL5_INT2_PAD_CTRL)
+            .await
+            .map_err(Error::Interface)?;
+        let ctrl7 = self
+            .read_reg_cached(Register::CTRL7)
+            .await
+            .map_err(Error::Interface)?;
+        let int2_on_int1 = ctrl7 & INT2_ON_INT1 != 0;
+        match (pin, source.int1_bit(), source.int2_bit()) {
+            (InterruptPin::Int1, Some(bit), _) => self
+                .reg_reset_bits(Register::CTRL4_INT1_PAD_CTRL, bit)
+                .await
+                .map_err(Error::Interface),
+            (InterruptPin::Int2, _, Some(bit)) if !int2_on_int1 => self
+                .reg_reset_bits(Register::CTRL5_INT2_PAD_CTRL, bit)
+                .await
+                .map_err(Error::Interface),
+            (InterruptPin::Int1, None, Some(bit)) if int2_on_int1 => {
+                self.reg_reset_bits(Register::CTRL5_INT2_PAD_CTRL, bit)
+                    .await
+                    .map_err(Error::Interface)?;
+                if ctrl5 & !bit == 0 {
+                    self.reg_reset_bits(Register::CTRL7, INT2_ON_INT1)
+                        .await
+                        .map_err(Error::Interface)?;
+                }
+                Ok(())
+            }
+            (InterruptPin::Int2, _, Some(_)) | (InterruptPin::Int1, None, Some(_)) => Ok(()),
+            _ => Err(Error::InvalidConfig),
+        }
+    }
+
+    /// Set the digital filter chain configuration
+    ///
+    /// Writes CTRL6 (BW_FILT, FDS and LOW_NOISE) and CTRL7 (HP_REF_MODE and LPASS_ON6D),
+    /// check [`FilterConfig::settling_transient`] for the number of samples to discard afterwards.
+    ///
+    /// # NOTE
+    ///
+    /// The registers are updated one after the other with a read-modify-write each, CTRL6 first and
+    /// CTRL7 second. Samples taken between the two writes use the new CTRL6 and the previous CTRL7 settings.
+    /// The other bits of CTRL7 (e.g. USR_OFF_ON_WU) are left unchanged.
+    ///
+    /// # Returns
+    ///
+    /// - The -3 dB cutoff of the output path in Hz for the currently set output data rate
+    ///   (see [`FilterConfig::cutoff_hz`])
+    pub async fn set_filter_config(
+        &mut self,
+        config: FilterConfig,
+    ) -> Result<Option<f32>, I::Error> {
+        let fds = match config.output {
+            OutputPath::LowPass => 0,
+            OutputPath::HighPass | OutputPath::HighPassReference => FDS,
+        };
+        let low_noise = if config.low_noise { LOW_NOISE } else { 0 };
+        self.modify_reg(Register::CTRL6, |v| {
+            v & !(BW_FILT_MASK | FDS | LOW_NOISE)
+                | (config.bandwidth as u8) << BW_FILT_SHIFT
+                | fds
+                | low_noise
+        })
+        .await?;
+        self.bandwidth = config.bandwidth;
+
+        let hp_ref_mode = match config.output {
+            OutputPath::HighPassReference => HP_REF_MODE,
+            OutputPath::LowPass | OutputPath::HighPass => 0,
+        };
+        let lpass_on6d = match config.six_d {
+            SixDPath::OdrDiv2 => 0,
+            SixDPath::LowPass => LPASS_ON6D,
+        };
+        self.modify_reg(Register::CTRL7, |v| {
+            v & !(HP_REF_MODE | LPASS_ON6D) | hp_ref_mode | lpass_on6d
+        })
+        .await?;
+
+        Ok(config.cutoff_hz(self.odr.as_hz(self.mode)))
+    }
+
     /// Enable/Disable Filtered data type selection
     ///
     /// disabled: low-pass filter path selected
@@ -254,6 +911,25 @@ impl<I: Interface> Lis2dtw12<I> {
         Ok(EventStatus::from(status))
     }
 
+    /// Get the sleep status
+    ///
+    /// Reads the sleep change event (edge, SLEEP_STATE_IA) from the STATUS_DUP register first and
+    /// the sleep state (level, SLEEP_STATE) from the STATUS register second, so the state is never
+    /// older than the event.
+    ///
+    /// # NOTE
+    ///
+    /// With latched interrupts (see [`Self::set_latched_interrupt`]) the sleep change event stays set
+    /// until WAKE_UP_SRC or ALL_INT_SRC is read, e.g. with [`Self::acknowledge`].
+    pub async fn get_sleep_status(&mut self) -> Result<SleepStatus, I::Error> {
+        let event_status = self.read_reg(Register::STATUS_DUP).await?;
+        let status = self.read_reg(Register::STATUS).await?;
+        Ok(SleepStatus {
+            sleeping: status & SLEEP_STATE != 0,
+            sleep_change_event: event_status & SLEEP_STATE_IA != 0,
+        })
+    }
+
     /// Get all source registers
     ///
     /// Reads the following registers succesively:
@@ -354,24 +1030,7 @@ impl<I: Interface> Lis2dtw12<I> {
     pub async fn get_accel_data_raw(&mut self) -> Result<RawAccelerationData, I::Error> {
         let mut buffer = [0; 6];
         self.read_regs(Register::OUT_X_L, &mut buffer).await?;
-        let raw_x = (buffer[1] as i16) << 8 | buffer[0] as i16;
-        let raw_y = (buffer[3] as i16) << 8 | buffer[2] as i16;
-        let raw_z = (buffer[5] as i16) << 8 | buffer[4] as i16;
-
-        match self.mode {
-            Mode::ContinuousLowPower1 | Mode::SingleConversionLowPower1 => {
-                Ok(RawAccelerationData {
-                    x: raw_x / 16,
-                    y: raw_y / 16,
-                    z: raw_z / 16,
-                })
-            }
-            _ => Ok(RawAccelerationData {
-                x: raw_x / 4,
-                y: raw_y / 4,
-                z: raw_z / 4,
-            }),
-        }
+        Ok(self.decode_accel_data_raw(&buffer))
     }
 
     /// Get the acceleration data
@@ -381,21 +1040,49 @@ impl<I: Interface> Lis2dtw12<I> {
     /// - `AccelerationData` struct containing the acceleration data in **mg**
     pub async fn get_accel_data(&mut self) -> Result<AccelerationData, I::Error> {
         let raw = self.get_accel_data_raw().await?;
-        Ok(AccelerationData {
-            x: self.fullscale.convert_raw_i16_to_mg(raw.x, self.mode),
-            y: self.fullscale.convert_raw_i16_to_mg(raw.y, self.mode),
-            z: self.fullscale.convert_raw_i16_to_mg(raw.z, self.mode),
+        Ok(raw.to_mg(self.fullscale, self.mode))
+    }
+
+    /// Read the Status register together with the acceleration data
+    ///
+    /// # NOTE
+    ///
+    /// STATUS and the output registers are contiguous and read in a single transaction,
+    /// so the status (e.g. [`SampleWithStatus::data_ready`]) always belongs to the returned sample
+    pub async fn read_sample_with_status(&mut self) -> Result<SampleWithStatus, I::Error> {
+        let mut buffer = [0; 7];
+        self.read_regs(Register::STATUS, &mut buffer).await?;
+        let [status, data @ ..] = buffer;
+        let raw = self.decode_accel_data_raw(&data);
+        Ok(SampleWithStatus {
+            status: Status::from(status),
+            raw,
+            data: raw.to_mg(self.fullscale, self.mode),
         })
     }
 
     /// Set the FIFO mode
     pub async fn set_fifo_mode(&mut self, fifo_mode: FifoMode) -> Result<(), I::Error> {
-        self.modify_reg(Register::CTRL3, |v| {
+        self.modify_reg(Register::FIFO_CTRL, |v| {
             v & !FMODE_MASK | (fifo_mode as u8) << FMODE_SHIFT
         })
         .await
     }
 
+    /// Get the FIFO mode
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_fifo_mode(&mut self) -> Result<FifoMode, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::FIFO_CTRL)
+            .await
+            .map_err(Error::Interface)?;
+        let value = FifoMode::try_from((v & FMODE_MASK) >> FMODE_SHIFT)?;
+        Ok(value)
+    }
+
     /// Set the FIFO threshold
     ///
     /// # NOTE
@@ -410,12 +1097,126 @@ impl<I: Interface> Lis2dtw12<I> {
             .await
     }
 
+    /// Get the FIFO threshold (0-31)
+    pub async fn get_fifo_threshold(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::FIFO_CTRL).await?;
+        Ok((v & FTH_MASK) >> FTH_SHIFT)
+    }
+
     /// Get the FIFO samples status
     pub async fn get_fifo_samples_status(&mut self) -> Result<FifoSamplesStatus, I::Error> {
         let status = self.read_reg(Register::FIFO_SAMPLES).await?;
         Ok(FifoSamplesStatus::from(status))
     }
 
+    /// Read the RAW acceleration data stored in the FIFO
+    ///
+    /// Reads as many samples as are available in the FIFO, but at most `buffer.len()`
+    ///
+    /// # Returns
+    ///
+    /// - Number of samples written to `buffer`
+    pub async fn read_fifo_raw(
+        &mut self,
+        buffer: &mut [RawAccelerationData],
+    ) -> Result<usize, I::Error> {
+        let status = self.get_fifo_samples_status().await?;
+        let count = (status.samples as usize).min(buffer.len());
+        for sample in buffer[..count].iter_mut() {
+            *sample = self.get_accel_data_raw().await?;
+        }
+        self.record_samples(count as u32, false, status.overrun);
+        Ok(count)
+    }
+
+    /// Arm the impact recorder
+    ///
+    /// Clears the FIFO, routes the trigger event to INT1, enables latched interrupts (LIR), so that
+    /// the trigger event is not missed between two polls, enables interrupts and
+    /// switches the FIFO to [`FifoMode::ContinuousToFifo`].
+    ///
+    /// The trigger event itself must be configured beforehand
+    /// (wake-up threshold/duration or free-fall configuration).
+    pub async fn arm_impact_recorder(
+        &mut self,
+        recorder: &mut ImpactRecorder,
+    ) -> Result<(), I::Error> {
+        let int1 = match recorder.config.trigger {
+            ImpactTrigger::WakeUp => INT1_WU,
+            ImpactTrigger::FreeFall => INT1_FF,
+        };
+        self.set_fifo_mode(FifoMode::Bypass).await?;
+        self.reg_set_bits(Register::CTRL4_INT1_PAD_CTRL, int1)
+            .await?;
+        self.set_latched_interrupt(true).await?;
+        self.reg_set_bits(Register::CTRL7, INTERRUPTS_ENABLE)
+            .await?;
+        self.set_fifo_mode(FifoMode::ContinuousToFifo).await?;
+        recorder.armed = true;
+        recorder.triggered = false;
+        Ok(())
+    }
+
+    /// Poll the impact recorder
+    ///
+    /// The trigger event is read from WAKE_UP_SRC, which clears the latched event. When it is detected,
+    /// the oldest samples are discarded to keep [`ImpactConfig::pre_trigger_samples`] samples before
+    /// the trigger. Once the FIFO is full again, it is drained into the recorder,
+    /// the recorder is disarmed and the FIFO is set to [`FifoMode::Bypass`].
+    ///
+    /// # NOTE
+    ///
+    /// The samples between the trigger and its detection are not recorded once the FIFO is full,
+    /// poll at least once per FIFO period (32 samples) to keep this gap short.
+    ///
+    /// # Returns
+    ///
+    /// - The report of the recorded impact, or `None` if no impact was recorded yet
+    pub async fn poll_impact_recorder(
+        &mut self,
+        recorder: &mut ImpactRecorder,
+    ) -> Result<Option<ImpactReport>, I::Error> {
+        if !recorder.armed {
+            return Ok(None);
+        }
+
+        if !recorder.triggered {
+            let source = self.get_wake_up_source().await?;
+            recorder.triggered = match recorder.config.trigger {
+                ImpactTrigger::WakeUp => source.wake_up_event,
+                ImpactTrigger::FreeFall => source.free_fall_event,
+            };
+            if !recorder.triggered {
+                return Ok(None);
+            }
+
+            // Discard the oldest samples, the FIFO collects the post-trigger samples in the freed space
+            let level = self.get_fifo_samples_status().await?.samples as usize;
+            for _ in 0..level.saturating_sub(recorder.config.pre_trigger()) {
+                self.get_accel_data_raw().await?;
+            }
+        }
+
+        // Wait for the post-trigger samples
+        if (self.get_fifo_samples_status().await?.samples as usize) < FIFO_SIZE {
+            return Ok(None);
+        }
+
+        recorder.len = self.read_fifo_raw(&mut recorder.samples).await?;
+        recorder.armed = false;
+        self.set_fifo_mode(FifoMode::Bypass).await?;
+
+        let mut samples = [AccelerationData::default(); FIFO_SIZE];
+        for (mg, raw) in samples.iter_mut().zip(recorder.samples()) {
+            *mg = raw.to_mg(self.fullscale, self.mode);
+        }
+        Ok(ImpactReport::analyze(
+            &samples[..recorder.len],
+            self.odr.as_hz(self.mode),
+            recorder.config.impact_threshold,
+        ))
+    }
+
     /// Enable 4D decection portrait/landscape position
     ///
     /// Disabled by default
@@ -441,6 +1242,20 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the 6D threshold
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_6d_threshold(&mut self) -> Result<Threshold6D, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::TAP_THS_X)
+            .await
+            .map_err(Error::Interface)?;
+        let value = Threshold6D::try_from((v & THS_6D_MASK) >> THS_6D_SHIFT)?;
+        Ok(value)
+    }
+
     /// Set the tap priority
     ///
     /// Tap Priority axis selection for tap detection
@@ -451,6 +1266,20 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the tap priority
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_tap_priority(&mut self) -> Result<TapPriority, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::TAP_THS_Y)
+            .await
+            .map_err(Error::Interface)?;
+        let value = TapPriority::try_from((v & TAP_PRIOR_MASK) >> TAP_PRIOR_SHIFT)?;
+        Ok(value)
+    }
+
     /// Enable X/Y/Z direction tap recognition
     ///
     /// # NOTE
@@ -486,6 +1315,12 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the tap threshold for X direction (0-31)
+    pub async fn get_x_tap_threshold(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::TAP_THS_X).await?;
+        Ok((v & TAP_THS_MASK) >> TAP_THS_SHIFT)
+    }
+
     /// Set the tap Threshold for Y direction
     ///
     /// # NOTE
@@ -501,6 +1336,12 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the tap threshold for Y direction (0-31)
+    pub async fn get_y_tap_threshold(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::TAP_THS_Y).await?;
+        Ok((v & TAP_THS_MASK) >> TAP_THS_SHIFT)
+    }
+
     /// Set the tap Threshold for Z direction
     ///
     /// # NOTE
@@ -516,6 +1357,12 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the tap threshold for Z direction (0-31)
+    pub async fn get_z_tap_threshold(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::TAP_THS_Z).await?;
+        Ok((v & TAP_THS_MASK) >> TAP_THS_SHIFT)
+    }
+
     /// Duration of maximum time gap for double-tap recognition. When double-tap recognition is enabled, this
     /// register expresses the maximum time between two successive detected taps to determine a double-tap event.
     ///
@@ -536,6 +1383,14 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the double-tap latency (0-15)
+    ///
+    /// 1 LSB = 32 * 1/ODR
+    pub async fn get_double_tap_latency(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::INT_DUR).await?;
+        Ok((v & LATENCY_MASK) >> LATENCY_SHIFT)
+    }
+
     /// Expected quiet time after a tap detection: this register represents the time after the first detected tap in which
     /// there must not be any overthreshold event.
     ///
@@ -554,6 +1409,14 @@ impl<I: Interface> Lis2dtw12<I> {
             .await
     }
 
+    /// Get the tap quiet time (0-3)
+    ///
+    /// 1 LSB = 4 * 1/ODR
+    pub async fn get_tap_quiet_time(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::INT_DUR).await?;
+        Ok((v & QUIET_MASK) >> QUIET_SHIFT)
+    }
+
     /// Maximum duration of overthreshold event: this register represents the maximum time of an overthreshold
     /// signal detection to be recognized as a tap event.
     ///
@@ -571,6 +1434,14 @@ impl<I: Interface> Lis2dtw12<I> {
             .await
     }
 
+    /// Get the tap shock time (0-3)
+    ///
+    /// 1 LSB = 8 * 1/ODR
+    pub async fn get_tap_shock_time(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::INT_DUR).await?;
+        Ok((v & SHOCK_MASK) >> SHOCK_SHIFT)
+    }
+
     /// Enable/Disable double-tap detection
     ///
     /// enabled: Single and double tap detection enabled
@@ -626,6 +1497,14 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the wake-up threshold (0-63)
+    ///
+    /// 1 LSB = 1/64 of FS
+    pub async fn get_wake_up_threshold(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::WAKE_UP_THS).await?;
+        Ok((v & WK_THS_MASK) >> WK_THS_SHIFT)
+    }
+
     /// Set the wake-up duration
     ///
     /// Wake-up duration. 1 LSB = 1 *1/ODR
@@ -643,6 +1522,14 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the wake-up duration (0-3)
+    ///
+    /// 1 LSB = 1 * 1/ODR
+    pub async fn get_wake_up_duration(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::WAKE_UP_DUR).await?;
+        Ok((v & WK_DUR_MASK) >> WK_DUR_SHIFT)
+    }
+
     /// Enable/Disable stationary detection / motion detection with no automatic ODR change
     /// when detecting stationary state
     ///
@@ -682,6 +1569,78 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the duration to go in sleep mode (0-15)
+    ///
+    /// 1 LSB = 512 * 1/ODR
+    pub async fn get_sleep_duration(&mut self) -> Result<u8, I::Error> {
+        let v = self.read_reg(Register::WAKE_UP_DUR).await?;
+        Ok((v & SLEEP_DUR_MASK) >> SLEEP_DUR_SHIFT)
+    }
+
+    /// Configure the activity/inactivity or stationary/motion detection
+    ///
+    /// Durations are converted to register values using the currently set output data rate
+    /// (see [`Self::set_output_data_rate`]), so the ODR should be set before calling this.
+    ///
+    /// # NOTE
+    ///
+    /// Durations are rounded to the closest possible value and limited to the available range
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the device is powered down
+    pub async fn configure_activity_detection(
+        &mut self,
+        config: ActivityConfig,
+    ) -> Result<(), Error<I::Error>> {
+        let odr = self.odr.as_hz(self.mode);
+        if odr == 0.0 {
+            return Err(Error::InvalidConfig);
+        }
+        let wake_up_duration = libm::roundf(config.wake_up_duration_s * odr).clamp(0.0, 3.0) as u8;
+        let sleep_duration =
+            libm::roundf(config.sleep_duration_s * odr / 512.0).clamp(0.0, 15.0) as u8;
+        let threshold = config.wake_up_threshold.clamp(0, 63);
+        let (sleep_on, stationary) = match config.detection {
+            ActivityDetection::Disabled => (0, 0),
+            ActivityDetection::ActivityInactivity => (SLEEP_ON, 0),
+            ActivityDetection::StationaryMotion => (SLEEP_ON, STATIONARY),
+        };
+
+        self.modify_reg(Register::WAKE_UP_DUR, |v| {
+            v & !(WK_DUR_MASK | STATIONARY | SLEEP_DUR_MASK)
+                | wake_up_duration << WK_DUR_SHIFT
+                | stationary
+                | sleep_duration << SLEEP_DUR_SHIFT
+        })
+        .await
+        .map_err(Error::Interface)?;
+        self.modify_reg(Register::WAKE_UP_THS, |v| {
+            v & !(SLEEP_ON | WK_THS_MASK) | sleep_on | threshold << WK_THS_SHIFT
+        })
+        .await
+        .map_err(Error::Interface)
+    }
+
+    /// Poll the sleep state and update the given activity monitor
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `monitor`: Activity monitor to update
+    /// - `now_ms`: Current timestamp in ms
+    ///
+    /// # Returns
+    ///
+    /// - The transition if the activity state changed since the last update
+    pub async fn poll_activity(
+        &mut self,
+        monitor: &mut ActivityMonitor,
+        now_ms: u64,
+    ) -> Result<Option<ActivityTransition>, I::Error> {
+        let status = self.get_status().await?;
+        Ok(monitor.update(status.sleep_event, now_ms))
+    }
+
     /// Set the free-fall duration
     ///
     /// 1 LSB = 1 * 1/ODR
@@ -701,11 +1660,24 @@ impl<I: Interface> Lis2dtw12<I> {
             self.reg_reset_bits(Register::WAKE_UP_DUR, FF_DUR5).await?;
         }
         self.modify_reg(Register::FREE_FALL, |v| {
-            v & !FF_DUR_MASK | d << FF_DUR_SHIFT
+            v & !FF_DUR_MASK | (d & 0b1_1111) << FF_DUR_SHIFT
         })
         .await
     }
 
+    /// Get the free-fall duration (0-63)
+    ///
+    /// 1 LSB = 1 * 1/ODR
+    pub async fn get_free_fall_duration(&mut self) -> Result<u8, I::Error> {
+        let wake_up_dur = self.read_reg(Register::WAKE_UP_DUR).await?;
+        let free_fall = self.read_reg(Register::FREE_FALL).await?;
+        let mut duration = (free_fall & FF_DUR_MASK) >> FF_DUR_SHIFT;
+        if wake_up_dur & FF_DUR5 != 0 {
+            duration |= 0b10_0000;
+        }
+        Ok(duration)
+    }
+
     /// Set the free-fall threshold
     ///
     /// Free-fall threshold @ FS = ±2 g
@@ -719,6 +1691,88 @@ impl<I: Interface> Lis2dtw12<I> {
         .await
     }
 
+    /// Get the free-fall threshold
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidValue`] if the register contains a reserved bit pattern
+    pub async fn get_free_fall_threshold(&mut self) -> Result<FreeFallThreshold, Error<I::Error>> {
+        let v = self
+            .read_reg(Register::FREE_FALL)
+            .await
+            .map_err(Error::Interface)?;
+        let value = FreeFallThreshold::try_from((v & FF_THS_MASK) >> FF_THS_SHIFT)?;
+        Ok(value)
+    }
+
+    /// Set the free-fall configuration
+    ///
+    /// The minimum duration is converted to 1/ODR steps using the currently set output data rate
+    /// (see [`Self::set_output_data_rate`]), so the ODR should be set before calling this.
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the device is powered down or the duration exceeds 63 * 1/ODR
+    pub async fn set_free_fall_config(
+        &mut self,
+        config: FreeFallConfig,
+    ) -> Result<(), Error<I::Error>> {
+        let odr = self.odr.as_hz(self.mode);
+        let duration = libm::roundf(config.min_duration_ms as f32 * odr / 1000.0);
+        if odr == 0.0 || duration > 63.0 {
+            return Err(Error::InvalidConfig);
+        }
+        let d = duration as u8;
+
+        self.modify_reg(Register::WAKE_UP_DUR, |v| {
+            if d & 0b10_0000 != 0 {
+                v | FF_DUR5
+            } else {
+                v & !FF_DUR5
+            }
+        })
+        .await
+        .map_err(Error::Interface)?;
+        self.modify_reg(Register::FREE_FALL, |v| {
+            v & !(FF_DUR_MASK | FF_THS_MASK)
+                | (d & 0b1_1111) << FF_DUR_SHIFT
+                | (config.threshold as u8) << FF_THS_SHIFT
+        })
+        .await
+        .map_err(Error::Interface)
+    }
+
+    /// Get the free-fall configuration
+    ///
+    /// The minimum duration is converted from 1/ODR steps to ms using the currently set output data rate
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the device is powered down
+    pub async fn get_free_fall_config(&mut self) -> Result<FreeFallConfig, Error<I::Error>> {
+        let odr = self.odr.as_hz(self.mode);
+        if odr == 0.0 {
+            return Err(Error::InvalidConfig);
+        }
+        let wake_up_dur = self
+            .read_reg(Register::WAKE_UP_DUR)
+            .await
+            .map_err(Error::Interface)?;
+        let free_fall = self
+            .read_reg(Register::FREE_FALL)
+            .await
+            .map_err(Error::Interface)?;
+
+        let mut duration = (free_fall & FF_DUR_MASK) >> FF_DUR_SHIFT;
+        if wake_up_dur & FF_DUR5 != 0 {
+            duration |= 0b10_0000;
+        }
+        Ok(FreeFallConfig {
+            threshold: FreeFallThreshold::try_from((free_fall & FF_THS_MASK) >> FF_THS_SHIFT)?,
+            min_duration_ms: libm::roundf(duration as f32 * 1000.0 / odr) as u16,
+        })
+    }
+
     /// Get the wake-up source
     pub async fn get_wake_up_source(&mut self) -> Result<WakeUpSource, I::Error> {
         let source = self.read_reg(Register::WAKE_UP_SRC).await?;
@@ -747,6 +1801,54 @@ impl<I: Interface> Lis2dtw12<I> {
         Ok(AllInterruptSources::from(source))
     }
 
+    /// Acknowledge all pending interrupt events
+    ///
+    /// Reads all source registers in a single burst (see [`Self::get_all_sources`]),
+    /// which clears all latched interrupt events.
+    ///
+    /// # Returns
+    ///
+    /// - The events that were pending
+    pub async fn acknowledge(&mut self) -> Result<PendingEvents, I::Error> {
+        let sources = self.get_all_sources().await?;
+        Ok(PendingEvents::from(sources))
+    }
+
+    /// Acknowledge only the selected interrupt events
+    ///
+    /// Reads only the source registers clearing the selected events, other latched events stay pending.
+    ///
+    /// # NOTE
+    ///
+    /// The events are cleared per source register: wake-up, free-fall and sleep change are all cleared by
+    /// WAKE_UP_SRC, single and double tap both by TAP_SRC. Selecting one of them also clears the others
+    /// of the same register, they are reported in the result.
+    ///
+    /// # Returns
+    ///
+    /// - The events that were pending in the read source registers
+    pub async fn acknowledge_only(
+        &mut self,
+        events: PendingEvents,
+    ) -> Result<PendingEvents, I::Error> {
+        let mut pending = PendingEvents::default();
+        if events.wake_up_src() {
+            let source = self.get_wake_up_source().await?;
+            pending.wake_up = source.wake_up_event;
+            pending.free_fall = source.free_fall_event;
+            pending.sleep_change = source.sleep_event;
+        }
+        if events.tap_src() {
+            let source = self.get_tap_source().await?;
+            pending.single_tap = source.single_tap_event;
+            pending.double_tap = source.double_tap_event;
+        }
+        if events.six_d {
+            pending.six_d = self.get_6d_source().await?.position_change_event;
+        }
+        Ok(pending)
+    }
+
     /// Set the X axis user offset value
     ///
     /// # ARGUMENTS
@@ -756,6 +1858,11 @@ impl<I: Interface> Lis2dtw12<I> {
         self.write_reg(Register::X_OFS_USR, offset as u8).await
     }
 
+    /// Get the X axis user offset value
+    pub async fn get_x_offset(&mut self) -> Result<i8, I::Error> {
+        Ok(self.read_reg(Register::X_OFS_USR).await? as i8)
+    }
+
     /// Set the Y axis user offset value
     ///
     /// # ARGUMENTS
@@ -765,6 +1872,11 @@ impl<I: Interface> Lis2dtw12<I> {
         self.write_reg(Register::Y_OFS_USR, offset as u8).await
     }
 
+    /// Get the Y axis user offset value
+    pub async fn get_y_offset(&mut self) -> Result<i8, I::Error> {
+        Ok(self.read_reg(Register::Y_OFS_USR).await? as i8)
+    }
+
     /// Set the Z axis user offset value
     ///
     /// # ARGUMENTS
@@ -774,6 +1886,27 @@ impl<I: Interface> Lis2dtw12<I> {
         self.write_reg(Register::Z_OFS_USR, offset as u8).await
     }
 
+    /// Get the Z axis user offset value
+    pub async fn get_z_offset(&mut self) -> Result<i8, I::Error> {
+        Ok(self.read_reg(Register::Z_OFS_USR).await? as i8)
+    }
+
+    /// Enable/Disable latched mode for the embedded function interrupts (LIR)
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `latched`: Latch the interrupts (true: latched - cleared by reading the source registers,
+    ///   false: pulsed - the source bits follow the event)
+    ///
+    /// Disabled by default
+    pub async fn set_latched_interrupt(&mut self, latched: bool) -> Result<(), I::Error> {
+        if latched {
+            self.reg_set_bits(Register::CTRL3, LIR).await
+        } else {
+            self.reg_reset_bits(Register::CTRL3, LIR).await
+        }
+    }
+
     /// Switch between latched and pulsed mode for data ready interrupt
     ///
     /// # ARGUMENTS
@@ -887,6 +2020,222 @@ impl<I: Interface> Lis2dtw12<I> {
         }
     }
 
+    /// Enable the high-pass filter reference mode, capturing the current acceleration as reference
+    ///
+    /// Selects the high-pass filter path (FDS) and enables HP_REF_MODE, which latches the reference
+    /// in the device. From then on the output data and the wake-up detection are relative to the reference.
+    ///
+    /// # NOTE
+    ///
+    /// The current output sample is read as the driver's copy of the reference, so the low-pass
+    /// filter path must be selected when calling this (default).
+    /// Use [`Self::recapture_reference`] to capture a new reference while in reference mode.
+    pub async fn enable_reference_mode(&mut self) -> Result<ReferenceMode, I::Error> {
+        let reference = self.get_accel_data().await?;
+        self.reg_set_bits(Register::CTRL6, FDS).await?;
+        self.reg_set_bits(Register::CTRL7, HP_REF_MODE).await?;
+        Ok(ReferenceMode { reference })
+    }
+
+    /// Capture the current acceleration as new reference while in reference mode
+    pub async fn recapture_reference(
+        &mut self,
+        reference: &mut ReferenceMode,
+    ) -> Result<(), I::Error> {
+        let deviation = self.get_accel_data().await?;
+        // The reference is latched when the mode is enabled
+        self.reg_reset_bits(Register::CTRL7, HP_REF_MODE).await?;
+        self.reg_set_bits(Register::CTRL7, HP_REF_MODE).await?;
+        reference.reference = AccelerationData {
+            x: reference.reference.x + deviation.x,
+            y: reference.reference.y + deviation.y,
+            z: reference.reference.z + deviation.z,
+        };
+        Ok(())
+    }
+
+    /// Disable the high-pass filter reference mode and select the low-pass filter path
+    pub async fn disable_reference_mode(&mut self) -> Result<(), I::Error> {
+        self.reg_reset_bits(Register::CTRL7, HP_REF_MODE).await?;
+        self.reg_reset_bits(Register::CTRL6, FDS).await
+    }
+
+    /// Read the deviation from the reference
+    pub async fn get_reference_deviation(
+        &mut self,
+        reference: &ReferenceMode,
+    ) -> Result<ReferenceDeviation, I::Error> {
+        let deviation = self.get_accel_data().await?;
+        Ok(reference.deviation(deviation))
+    }
+
+    /// Set the wake-up threshold in **mg**
+    ///
+    /// In reference mode the wake-up detection compares the deviation from the reference with the threshold,
+    /// use [`ReferenceMode::tilt_to_mg`] to get the threshold for a tilt angle.
+    ///
+    /// # NOTE
+    ///
+    /// The threshold is converted to 1/64 of the currently set full-scale and limited to 63 LSB
+    ///
+    /// # Returns
+    ///
+    /// - The effective threshold in **mg**
+    pub async fn set_reference_wake_up_threshold(
+        &mut self,
+        threshold_mg: f32,
+    ) -> Result<f32, I::Error> {
+        let lsb = self.fullscale.range_mg() / 64.0;
+        let threshold = libm::roundf(threshold_mg / lsb).clamp(0.0, 63.0) as u8;
+        self.set_wake_up_threshold(threshold).await?;
+        Ok(threshold as f32 * lsb)
+    }
+
+    /// Arm the tamper detector
+    ///
+    /// Captures the current pose as reference (see [`Self::enable_reference_mode`]), sets the
+    /// wake-up threshold to the motion threshold, routes the wake-up event to INT1 and enables interrupts.
+    ///
+    /// # NOTE
+    ///
+    /// The asset should be at rest in its installed pose when arming the detector
+    pub async fn arm_tamper_detector(
+        &mut self,
+        detector: &mut TamperDetector,
+    ) -> Result<(), I::Error> {
+        let reference = match detector.reference {
+            Some(mut reference) => {
+                self.recapture_reference(&mut reference).await?;
+                reference
+            }
+            None => self.enable_reference_mode().await?,
+        };
+        self.set_reference_wake_up_threshold(detector.config.motion_threshold_mg)
+            .await?;
+        self.reg_set_bits(Register::CTRL4_INT1_PAD_CTRL, INT1_WU)
+            .await?;
+        self.reg_set_bits(Register::CTRL7, INTERRUPTS_ENABLE)
+            .await?;
+        detector.reference = Some(reference);
+        detector.tilted = false;
+        Ok(())
+    }
+
+    /// Disarm the tamper detector and disable the reference mode
+    pub async fn disarm_tamper_detector(
+        &mut self,
+        detector: &mut TamperDetector,
+    ) -> Result<(), I::Error> {
+        self.disable_reference_mode().await?;
+        detector.reference = None;
+        detector.tilted = false;
+        Ok(())
+    }
+
+    /// Poll the tamper detector
+    ///
+    /// # NOTE
+    ///
+    /// Consider enabling latched interrupts, so that a wake-up event is not missed between two polls
+    ///
+    /// # Returns
+    ///
+    /// - The tamper event, or `None` if the detector is not armed or nothing happened since the last poll
+    pub async fn poll_tamper_detector(
+        &mut self,
+        detector: &mut TamperDetector,
+    ) -> Result<Option<TamperEvent>, I::Error> {
+        let Some(reference) = detector.reference else {
+            return Ok(None);
+        };
+        let moved = self.get_wake_up_source().await?.wake_up_event;
+        let deviation = self.get_reference_deviation(&reference).await?;
+        Ok(detector.update(deviation, moved))
+    }
+
+    /// Configure the wake-up detection to trigger when the device is tilted away from a reference
+    ///
+    /// Programs the gravity vector of the reference into the user offset registers (selecting the
+    /// offset weight that fits), applies the offsets to the wake-up function only, sets the wake-up
+    /// threshold for the angle, routes the wake-up event to INT1 and enables interrupts.
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `angle_deg`: Tilt angle in degrees (0-180)
+    /// - `reference`: Reference gravity vector, [`TiltReference::Current`] reads the current acceleration
+    ///   (which should not have the user offset applied, see [`Self::enable_user_offset_on_output`])
+    ///
+    /// # NOTE
+    ///
+    /// The wake-up comparator works per axis, so depending on the direction of the tilt
+    /// the event can trigger at a larger angle than configured
+    ///
+    /// # Returns
+    ///
+    /// - The effective angle in degrees after quantizing the threshold
+    ///
+    /// # Errors
+    ///
+    /// - [`Error::InvalidConfig`] if the angle is out of range or the reference is (close to) zero
+    pub async fn configure_tilt_wake(
+        &mut self,
+        angle_deg: f32,
+        reference: TiltReference,
+    ) -> Result<f32, Error<I::Error>> {
+        // User offset weights in mg/LSB
+        const LOW_OFFSET_WEIGHT: f32 = 0.977;
+        const HIGH_OFFSET_WEIGHT: f32 = 15.6;
+
+        let gravity = match reference {
+            TiltReference::Current => self.get_accel_data().await.map_err(Error::Interface)?,
+            TiltReference::Acceleration(acceleration) => acceleration,
+        };
+        let gravity_mg = gravity.magnitude();
+        if !(0.0..=180.0).contains(&angle_deg) || gravity_mg < HIGH_OFFSET_WEIGHT {
+            return Err(Error::InvalidConfig);
+        }
+
+        let largest = libm::fabsf(gravity.x)
+            .max(libm::fabsf(gravity.y))
+            .max(libm::fabsf(gravity.z));
+        let (weight, high_weight) = if largest <= i8::MAX as f32 * LOW_OFFSET_WEIGHT {
+            (LOW_OFFSET_WEIGHT, 0)
+        } else {
+            (HIGH_OFFSET_WEIGHT, USR_OFF_W)
+        };
+        let offset = |v: f32| libm::roundf(v / weight).clamp(-127.0, 127.0) as i8;
+        self.set_x_offset(offset(gravity.x))
+            .await
+            .map_err(Error::Interface)?;
+        self.set_y_offset(offset(gravity.y))
+            .await
+            .map_err(Error::Interface)?;
+        self.set_z_offset(offset(gravity.z))
+            .await
+            .map_err(Error::Interface)?;
+
+        let lsb = self.fullscale.range_mg() / 64.0;
+        let threshold = libm::roundf(TiltReference::tilt_to_mg(gravity_mg, angle_deg) / lsb)
+            .clamp(1.0, 63.0) as u8;
+        self.set_wake_up_threshold(threshold)
+            .await
+            .map_err(Error::Interface)?;
+
+        self.reg_set_bits(Register::CTRL4_INT1_PAD_CTRL, INT1_WU)
+            .await
+            .map_err(Error::Interface)?;
+        self.modify_reg(Register::CTRL7, |v| {
+            v & !USR_OFF_W | high_weight | USR_OFF_ON_WU | INTERRUPTS_ENABLE
+        })
+        .await
+        .map_err(Error::Interface)?;
+
+        Ok(TiltReference::mg_to_tilt(
+            gravity_mg,
+            threshold as f32 * lsb,
+        ))
+    }
+
     /// Enable/Disable low-pass filter for 6D interrupt function
     ///
     /// # ARGUMENTS
@@ -909,6 +2258,36 @@ impl<I: Interface> Lis2dtw12<I> {
         }
     }
 
+    /// Read a register as its typed value (see [`register_access`])
+    pub async fn read_register<R: RegisterValue>(&mut self) -> Result<R, I::Error> {
+        Ok(R::from(self.read_reg(R::REGISTER).await?))
+    }
+
+    /// Write a typed value to a register (see [`register_access`])
+    ///
+    /// # NOTE
+    ///
+    /// The settings cached by the driver (mode, full-scale, ODR and bandwidth) are not updated,
+    /// use the high-level setters to change CTRL1 and CTRL6, or update the cached settings
+    /// afterwards with the getters (e.g. [`Self::get_mode`]).
+    pub async fn write_register<R: WritableRegister>(&mut self, value: R) -> Result<(), I::Error> {
+        self.write_reg(R::REGISTER, value.into()).await
+    }
+
+    /// Read-modify-write a register using its typed value (see [`register_access`])
+    ///
+    /// # NOTE
+    ///
+    /// The settings cached by the driver (mode, full-scale, ODR and bandwidth) are not updated,
+    /// use the high-level setters to change CTRL1 and CTRL6, or update the cached settings
+    /// afterwards with the getters (e.g. [`Self::get_mode`]).
+    pub async fn modify_register<R: WritableRegister, F: FnOnce(R) -> R>(
+        &mut self,
+        f: F,
+    ) -> Result<(), I::Error> {
+        self.modify_reg(R::REGISTER, |v| f(R::from(v)).into()).await
+    }
+
     /// Dump all registers
     pub async fn dump_registers(&mut self) -> Result<(), I::Error> {
         let val = self.read_reg(Register::CTRL1).await?;
@@ -1023,6 +2402,113 @@ impl<I: Interface> Lis2dtw12<I> {
         Ok(())
     }
 
+    #[inline]
+    fn decode_accel_data_raw(&self, buffer: &[u8; 6]) -> RawAccelerationData {
+        let raw_x = (buffer[1] as i16) << 8 | buffer[0] as i16;
+        let raw_y = (buffer[3] as i16) << 8 | buffer[2] as i16;
+        let raw_z = (buffer[5] as i16) << 8 | buffer[4] as i16;
+
+        match self.mode {
+            Mode::ContinuousLowPower1 | Mode::SingleConversionLowPower1 => RawAccelerationData {
+                x: raw_x / 16,
+                y: raw_y / 16,
+                z: raw_z / 16,
+            },
+            _ => RawAccelerationData {
+                x: raw_x / 4,
+                y: raw_y / 4,
+                z: raw_z / 4,
+            },
+        }
+    }
+
+    async fn self_test_measure<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_us: u32,
+    ) -> Result<SelfTestResult, Error<I::Error>> {
+        const SETTLING_TIME_MS: u32 = 100;
+
+        self.write_reg(
+            Register::CTRL1,
+            (OutputDataRate::Hz50 as u8) << ODR_SHIFT | Mode::HighPerformance as u8,
+        )
+        .await
+        .map_err(Error::Interface)?;
+        self.mode = Mode::HighPerformance;
+        self.odr = OutputDataRate::Hz50;
+        self.write_reg(Register::CTRL6, (FullScale::G4 as u8) << FS_SHIFT)
+            .await
+            .map_err(Error::Interface)?;
+        self.fullscale = FullScale::G4;
+        self.bandwidth = BandwidthSelection::OdrDiv2;
+
+        self.set_self_test(SelfTest::Disabled)
+            .await
+            .map_err(Error::Interface)?;
+        delay.delay_ms(SETTLING_TIME_MS).await;
+        let normal = self.average_samples(delay, timeout_us).await?;
+
+        self.set_self_test(SelfTest::Positive)
+            .await
+            .map_err(Error::Interface)?;
+        delay.delay_ms(SETTLING_TIME_MS).await;
+        let self_test = self.average_samples(delay, timeout_us).await?;
+
+        Ok(SelfTestResult { normal, self_test })
+    }
+
+    async fn average_samples<D: DelayNs>(
+        &mut self,
+        delay: &mut D,
+        timeout_us: u32,
+    ) -> Result<AccelerationData, Error<I::Error>> {
+        const SAMPLES: u8 = 5;
+
+        // The first sample after a configuration change is discarded
+        self.wait_data_ready(delay, timeout_us).await?;
+        self.get_accel_data_raw().await.map_err(Error::Interface)?;
+
+        let mut sum = AccelerationData::default();
+        for _ in 0..SAMPLES {
+            self.wait_data_ready(delay, timeout_us).await?;
+            let data = self.get_accel_data().await.map_err(Error::Interface)?;
+            sum.x += data.x;
+            sum.y += data.y;
+            sum.z += data.z;
+        }
+        Ok(AccelerationData {
+            x: sum.x / SAMPLES as f32,
+            y: sum.y / SAMPLES as f32,
+            z: sum.z / SAMPLES as f32,
+        })
+    }
+
+    /// Poll a register every `interval_us` until `done` returns true for its value or the timeout expires
+    ///
+    /// Returns the last value read
+    async fn wait_for<D: DelayNs, F: Fn(u8) -> bool>(
+        &mut self,
+        reg: Register,
+        delay: &mut D,
+        interval_us: u32,
+        timeout_us: u32,
+        done: F,
+    ) -> Result<u8, Error<I::Error>> {
+        let mut elapsed_us = 0;
+        loop {
+            let value = self.read_reg(reg).await.map_err(Error::Interface)?;
+            if done(value) {
+                return Ok(value);
+            }
+            if elapsed_us >= timeout_us {
+                return Err(Error::Timeout);
+            }
+            delay.delay_us(interval_us).await;
+            elapsed_us = elapsed_us.saturating_add(interval_us);
+        }
+    }
+
     #[inline]
     async fn read_reg(&mut self, reg: Register) -> Result<u8, I::Error> {
         let mut data = [0];
@@ -1040,7 +2526,19 @@ impl<I: Interface> Lis2dtw12<I> {
 
     #[inline]
     async fn write_reg(&mut self, reg: Register, data: u8) -> Result<(), I::Error> {
-        self.interface.write(&[reg.addr(), data]).await
+        if self.cache.get(reg) == Some(data) {
+            return Ok(());
+        }
+        match self.interface.write(&[reg.addr(), data]).await {
+            Ok(()) => {
+                self.cache.update(reg, data);
+                Ok(())
+            }
+            Err(e) => {
+                self.cache.remove(reg);
+                Err(e)
+            }
+        }
     }
 
     #[inline]
@@ -1049,10 +2547,18 @@ impl<I: Interface> Lis2dtw12<I> {
         reg: Register,
         f: F,
     ) -> Result<(), I::Error> {
-        let r = self.read_reg(reg).await?;
+        let r = self.read_reg_cached(reg).await?;
         self.write_reg(reg, f(r)).await
     }
 
+    #[inline]
+    async fn read_reg_cached(&mut self, reg: Register) -> Result<u8, I::Error> {
+        match self.cache.get(reg) {
+            Some(r) => Ok(r),
+            None => self.read_reg(reg).await,
+        }
+    }
+
     #[inline]
     async fn reg_set_bits(&mut self, reg: Register, mask: u8) -> Result<(), I::Error> {
         self.modify_reg(reg, |r| r | mask).await
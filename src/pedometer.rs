@@ -0,0 +1,235 @@
+use crate::{FullScale, Mode, OutputDataRate, RawAccelerationData};
+
+/// Number of step intervals used for the cadence estimation
+const CADENCE_WINDOW: usize = 4;
+
+/// Pedometer configuration
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PedometerConfig {
+    /// Output data rate the samples are taken with (25 Hz or 50 Hz recommended)
+    pub odr: OutputDataRate,
+    /// Mode the samples are taken with
+    pub mode: Mode,
+    /// Full-scale the samples are taken with
+    pub full_scale: FullScale,
+    /// Lower cutoff frequency of the band-pass filter in Hz
+    ///
+    /// Default value is 0.5 Hz (removes gravity)
+    pub low_cutoff_hz: f32,
+    /// Upper cutoff frequency of the band-pass filter in Hz
+    ///
+    /// Default value is 3 Hz (removes impacts and sensor noise)
+    pub high_cutoff_hz: f32,
+    /// Minimum peak height of the filtered magnitude to be counted as a step in **mg**
+    ///
+    /// Default value is 60 mg
+    pub min_peak_threshold: f32,
+    /// Minimum time between two steps in ms
+    ///
+    /// Default value is 250 ms (240 steps/min)
+    pub min_step_interval_ms: u16,
+    /// Maximum time between two steps in ms, after which the cadence is reset
+    ///
+    /// Default value is 2000 ms (30 steps/min)
+    pub max_step_interval_ms: u16,
+}
+
+impl Default for PedometerConfig {
+    fn default() -> Self {
+        Self {
+            odr: OutputDataRate::Hz25,
+            mode: Mode::default(),
+            full_scale: FullScale::default(),
+            low_cutoff_hz: 0.5,
+            high_cutoff_hz: 3.0,
+            min_peak_threshold: 60.0,
+            min_step_interval_ms: 250,
+            max_step_interval_ms: 2000,
+        }
+    }
+}
+
+/// Software pedometer
+///
+/// The LIS2DTW12 has no embedded pedometer, this implements step detection on the host
+/// using samples drained from the FIFO (or read one by one).
+///
+/// The algorithm works on the magnitude of the acceleration vector, which makes it independent
+/// of the orientation of the sensor:
+/// 1. Magnitude computation in **mg**
+/// 2. Band-pass filtering (first-order high-pass followed by a first-order low-pass)
+/// 3. Peak detection with an adaptive threshold (half the average height of the last peaks)
+/// 4. Step counting and cadence estimation from the last step intervals
+///
+/// All state has a fixed size, no allocation is needed.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Pedometer {
+    config: PedometerConfig,
+    sample_rate: f32,
+    hp_alpha: f32,
+    lp_alpha: f32,
+    envelope_decay: f32,
+    min_step_interval: u32,
+    max_step_interval: u32,
+    primed: bool,
+    last_magnitude: f32,
+    high_pass: f32,
+    low_pass: f32,
+    previous: [f32; 2],
+    envelope: f32,
+    samples_since_step: u32,
+    intervals: [u32; CADENCE_WINDOW],
+    interval_count: usize,
+    interval_index: usize,
+    step_count: u32,
+}
+
+impl Pedometer {
+    /// Create a new pedometer from the given configuration
+    ///
+    /// # Returns
+    ///
+    /// - The pedometer, or `None` if the output data rate is power-down
+    pub fn new(config: PedometerConfig) -> Option<Self> {
+        let sample_rate = config.odr.as_hz(config.mode);
+        if sample_rate == 0.0 {
+            return None;
+        }
+        let dt = 1.0 / sample_rate;
+        let hp_rc = 1.0 / (2.0 * core::f32::consts::PI * config.low_cutoff_hz);
+        let lp_rc = 1.0 / (2.0 * core::f32::consts::PI * config.high_cutoff_hz);
+
+        Some(Self {
+            config,
+            sample_rate,
+            hp_alpha: hp_rc / (hp_rc + dt),
+            lp_alpha: dt / (lp_rc + dt),
+            // Envelope decays to ~37% in 2 seconds without new peaks
+            envelope_decay: libm::expf(-dt / 2.0),
+            min_step_interval: ms_to_samples(config.min_step_interval_ms, sample_rate),
+            max_step_interval: ms_to_samples(config.max_step_interval_ms, sample_rate),
+            primed: false,
+            last_magnitude: 0.0,
+            high_pass: 0.0,
+            low_pass: 0.0,
+            previous: [0.0; 2],
+            envelope: 0.0,
+            samples_since_step: 0,
+            intervals: [0; CADENCE_WINDOW],
+            interval_count: 0,
+            interval_index: 0,
+            step_count: 0,
+        })
+    }
+
+    /// Get the configuration of the pedometer
+    pub fn config(&self) -> &PedometerConfig {
+        &self.config
+    }
+
+    /// Process a batch of RAW samples (e.g. a FIFO drain)
+    ///
+    /// # Returns
+    ///
+    /// - Number of steps detected in this batch
+    pub fn process(&mut self, samples: &[RawAccelerationData]) -> u32 {
+        samples
+            .iter()
+            .filter(|sample| self.process_sample(sample))
+            .count() as u32
+    }
+
+    /// Process a single RAW sample
+    ///
+    /// # Returns
+    ///
+    /// - `true` if a step was detected
+    pub fn process_sample(&mut self, sample: &RawAccelerationData) -> bool {
+        let data = sample.to_mg(self.config.full_scale, self.config.mode);
+        self.process_magnitude(data.magnitude())
+    }
+
+    /// Process a single acceleration magnitude in **mg**
+    ///
+    /// # Returns
+    ///
+    /// - `true` if a step was detected
+    pub fn process_magnitude(&mut self, magnitude: f32) -> bool {
+        if !self.primed {
+            // Start the high-pass filter at the first magnitude to avoid a gravity step response
+            self.last_magnitude = magnitude;
+            self.primed = true;
+        }
+
+        self.high_pass = self.hp_alpha * (self.high_pass + magnitude - self.last_magnitude);
+        self.last_magnitude = magnitude;
+        self.low_pass += self.lp_alpha * (self.high_pass - self.low_pass);
+
+        let current = self.low_pass;
+        let [candidate, before] = self.previous;
+        self.previous = [current, candidate];
+
+        self.envelope *= self.envelope_decay;
+        self.samples_since_step = self.samples_since_step.saturating_add(1);
+        if self.samples_since_step > self.max_step_interval {
+            // Walking stopped, forget the cadence
+            self.interval_count = 0;
+            self.interval_index = 0;
+        }
+
+        let is_peak = candidate > before && candidate >= current;
+        if !is_peak || candidate < self.config.min_peak_threshold {
+            return false;
+        }
+
+        // The peak was one sample ago
+        let interval = self.samples_since_step - 1;
+        let threshold = self.envelope * 0.5;
+        self.envelope += (candidate - self.envelope) * 0.25;
+        if candidate < threshold || interval < self.min_step_interval {
+            return false;
+        }
+
+        if self.step_count > 0 && interval <= self.max_step_interval {
+            self.intervals[self.interval_index] = interval;
+            self.interval_index = (self.interval_index + 1) % CADENCE_WINDOW;
+            self.interval_count = (self.interval_count + 1).min(CADENCE_WINDOW);
+        }
+        self.samples_since_step = 1;
+        self.step_count = self.step_count.wrapping_add(1);
+        true
+    }
+
+    /// Get the total number of detected steps
+    pub fn step_count(&self) -> u32 {
+        self.step_count
+    }
+
+    /// Get the current cadence
+    ///
+    /// # Returns
+    ///
+    /// - Cadence in **steps/min**, or `None` if not walking
+    pub fn cadence(&self) -> Option<f32> {
+        if self.interval_count == 0 || self.samples_since_step > self.max_step_interval {
+            return None;
+        }
+        let total: u32 = self.intervals[..self.interval_count].iter().sum();
+        let mean = total as f32 / self.interval_count as f32;
+        Some(60.0 * self.sample_rate / mean)
+    }
+
+    /// Reset the step count and all filter states
+    pub fn reset(&mut self) {
+        // The configuration was validated when the pedometer was created
+        if let Some(pedometer) = Self::new(self.config) {
+            *self = pedometer;
+        }
+    }
+}
+
+fn ms_to_samples(ms: u16, sample_rate: f32) -> u32 {
+    (ms as f32 * sample_rate / 1000.0) as u32
+}
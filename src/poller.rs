@@ -0,0 +1,130 @@
+#[cfg(feature = "blocking")]
+use embedded_hal::delay::DelayNs;
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs;
+
+use crate::registers::*;
+#[cfg(feature = "blocking")]
+use crate::Lis2dtw12;
+#[cfg(feature = "async")]
+use crate::Lis2dtw12Async;
+use crate::{AllSources, Interface, PendingEvents};
+
+/// Poll interval in µs used while the device is powered down (one sample at 12.5 Hz)
+const POWER_DOWN_INTERVAL_US: u32 = 80_000;
+
+/// Event poller for boards without the interrupt pins wired
+///
+/// Polls ALL_INT_SRC at the output data rate and reads the other source registers only when an event
+/// is pending, yielding the same [`PendingEvents`] as [`Lis2dtw12::acknowledge`] would in an interrupt handler.
+///
+/// With latched interrupts (see [`Lis2dtw12::set_latched_interrupt`]) reading the sources clears them,
+/// so every event read back is reported. With pulsed interrupts the source bits follow the event,
+/// so events still set on consecutive polls are only reported once.
+///
+/// # NOTE
+///
+/// The interrupt mode (LIR) is read on the first poll, create a new poller after changing it.
+#[maybe_async_cfg::maybe(sync(feature = "blocking", keep_self), async(feature = "async"))]
+pub struct EventPoller<D> {
+    delay: D,
+    interval_us: Option<u32>,
+    /// Latched interrupts (LIR), read on the first poll
+    latched: Option<bool>,
+    previous: PendingEvents,
+}
+
+#[maybe_async_cfg::maybe(
+    idents(Lis2dtw12(sync = "Lis2dtw12", async = "Lis2dtw12Async")),
+    sync(feature = "blocking", keep_self),
+    async(feature = "async")
+)]
+impl<D: DelayNs> EventPoller<D> {
+    /// Create a new event poller, polling once per sample period of the current output data rate
+    pub fn new(delay: D) -> Self {
+        Self {
+            delay,
+            interval_us: None,
+            latched: None,
+            previous: PendingEvents::default(),
+        }
+    }
+
+    /// Use a fixed poll interval instead of the sample period
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `interval_us`: Poll interval in µs
+    pub fn with_interval_us(mut self, interval_us: u32) -> Self {
+        self.interval_us = Some(interval_us);
+        self
+    }
+
+    /// Release the delay
+    pub fn release(self) -> D {
+        self.delay
+    }
+
+    /// Poll the source registers once
+    ///
+    /// # Returns
+    ///
+    /// - The events that became pending since the last poll (may be empty)
+    pub async fn poll<I: Interface>(
+        &mut self,
+        device: &mut Lis2dtw12<I>,
+    ) -> Result<PendingEvents, I::Error> {
+        let latched = match self.latched {
+            Some(latched) => latched,
+            None => {
+                let latched = device.read_reg_cached(Register::CTRL3).await? & LIR != 0;
+                self.latched = Some(latched);
+                latched
+            }
+        };
+        let all_int_src = device.read_reg(Register::ALL_INT_SRC).await?;
+        let current = if all_int_src == 0 {
+            PendingEvents::default()
+        } else {
+            // Reading ALL_INT_SRC clears the latched events, keep its value for the detailed sources
+            let mut sources = [0; 5];
+            device.read_regs(Register::STATUS_DUP, &mut sources).await?;
+            sources[4] |= all_int_src;
+            PendingEvents::from(AllSources::from(sources))
+        };
+
+        if latched {
+            self.previous = PendingEvents::default();
+            return Ok(current);
+        }
+        let new = current.difference(&self.previous);
+        self.previous = current;
+        Ok(new)
+    }
+
+    /// Wait for the next events
+    ///
+    /// Polls the source registers until at least one new event is pending
+    ///
+    /// # NOTE
+    ///
+    /// While the device is powered down the source registers are polled every 80 ms
+    pub async fn next<I: Interface>(
+        &mut self,
+        device: &mut Lis2dtw12<I>,
+    ) -> Result<PendingEvents, I::Error> {
+        loop {
+            let events = self.poll(device).await?;
+            if !events.is_empty() {
+                return Ok(events);
+            }
+            let interval_us =
+                self.interval_us
+                    .unwrap_or_else(|| match device.odr.as_hz(device.mode) {
+                        hz if hz > 0.0 => libm::ceilf(1_000_000.0 / hz) as u32,
+                        _ => POWER_DOWN_INTERVAL_US,
+                    });
+            self.delay.delay_us(interval_us).await;
+        }
+    }
+}
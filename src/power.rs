@@ -0,0 +1,144 @@
+use crate::{Mode, OutputDataRate};
+
+/// Power configuration (mode, output data rate and low-noise setting)
+///
+/// Can be applied with [`Lis2dtw12::set_power_config`](crate::Lis2dtw12::set_power_config),
+/// which rejects combinations not supported by the device (see [`PowerConfig::validate`])
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct PowerConfig {
+    /// Operating mode
+    pub mode: Mode,
+    /// Output data rate
+    pub odr: OutputDataRate,
+    /// Low-noise configuration (LOW_NOISE)
+    pub low_noise: bool,
+}
+
+/// Reason a power configuration is not supported
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum PowerConfigError {
+    /// 1.6 Hz is only available in the low-power modes
+    HighPerformanceOdr,
+    /// The continuous low-power modes are limited to 200 Hz
+    LowPowerOdr,
+    /// The single data conversion modes require [`OutputDataRate::Hz12_5`] (or power-down)
+    SingleConversionOdr,
+}
+
+/// Typical noise of a power configuration
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TypicalNoise {
+    /// Noise density in µg/√Hz (high-performance mode)
+    Density(f32),
+    /// RMS noise in **mg** (low-power modes)
+    Rms(f32),
+}
+
+impl PowerConfig {
+    /// Check if the combination of mode and output data rate is supported
+    ///
+    /// # Returns
+    ///
+    /// - The effective output data rate in Hz (0 when powered down, the maximum conversion
+    ///   rate for the single data conversion modes)
+    pub fn validate(&self) -> Result<f32, PowerConfigError> {
+        match (self.mode, self.odr) {
+            (_, OutputDataRate::PowerDown) => Ok(0.0),
+            (Mode::HighPerformance, OutputDataRate::Hz1_6) => {
+                Err(PowerConfigError::HighPerformanceOdr)
+            }
+            (Mode::HighPerformance, odr) => Ok(odr.as_hz(self.mode)),
+            (
+                Mode::SingleConversionLowPower1
+                | Mode::SingleConversionLowPower2
+                | Mode::SingleConversionLowPower3
+                | Mode::SingleConversionLowPower4,
+                OutputDataRate::Hz12_5,
+            ) => Ok(12.5),
+            (
+                Mode::SingleConversionLowPower1
+                | Mode::SingleConversionLowPower2
+                | Mode::SingleConversionLowPower3
+                | Mode::SingleConversionLowPower4,
+                _,
+            ) => Err(PowerConfigError::SingleConversionOdr),
+            (_, OutputDataRate::Hz400 | OutputDataRate::Hz800 | OutputDataRate::Hz1600) => {
+                Err(PowerConfigError::LowPowerOdr)
+            }
+            (_, odr) => Ok(odr.as_hz(self.mode)),
+        }
+    }
+
+    /// Get the typical current consumption in µA (Vdd = 1.8 V)
+    ///
+    /// Approximate values from the datasheet, for the single data conversion modes
+    /// conversions are assumed to be triggered at the maximum rate
+    ///
+    /// # Returns
+    ///
+    /// - The current, or `None` if the configuration is not supported
+    pub fn typical_current_ua(&self) -> Option<f32> {
+        // Low-power modes 1-4 at 1.6, 12.5, 25, 50, 100 and 200 Hz
+        const LOW_POWER_CURRENT: [[f32; 6]; 4] = [
+            [0.38, 1.0, 1.5, 3.0, 5.0, 10.0],
+            [0.5, 1.3, 2.3, 4.5, 9.0, 17.5],
+            [0.67, 1.9, 3.7, 7.3, 14.0, 28.0],
+            [0.9, 3.5, 7.0, 14.0, 27.0, 54.0],
+        ];
+        const HIGH_PERFORMANCE_CURRENT: f32 = 90.0;
+        const POWER_DOWN_CURRENT: f32 = 0.05;
+
+        let odr_hz = self.validate().ok()?;
+        if odr_hz == 0.0 {
+            return Some(POWER_DOWN_CURRENT);
+        }
+        let lp_mode = match self.mode {
+            Mode::HighPerformance => return Some(HIGH_PERFORMANCE_CURRENT),
+            Mode::ContinuousLowPower1 | Mode::SingleConversionLowPower1 => 0,
+            Mode::ContinuousLowPower2 | Mode::SingleConversionLowPower2 => 1,
+            Mode::ContinuousLowPower3 | Mode::SingleConversionLowPower3 => 2,
+            Mode::ContinuousLowPower4 | Mode::SingleConversionLowPower4 => 3,
+        };
+        let odr_index = match self.odr {
+            OutputDataRate::Hz1_6 => 0,
+            OutputDataRate::Hz12_5 => 1,
+            OutputDataRate::Hz25 => 2,
+            OutputDataRate::Hz50 => 3,
+            OutputDataRate::Hz100 => 4,
+            _ => 5,
+        };
+        Some(LOW_POWER_CURRENT[lp_mode][odr_index])
+    }
+
+    /// Get the typical noise @ FS = ±2 g
+    ///
+    /// Approximate values from the datasheet, the datasheet specifies the noise density for the
+    /// high-performance mode and the RMS noise for the low-power modes
+    ///
+    /// # Returns
+    ///
+    /// - The noise, or `None` if the configuration is not supported or the device is powered down
+    pub fn typical_noise(&self) -> Option<TypicalNoise> {
+        if self.validate().ok()? == 0.0 {
+            return None;
+        }
+        let (normal, low_noise) = match self.mode {
+            Mode::HighPerformance => {
+                let density = if self.low_noise { 90.0 } else { 110.0 };
+                return Some(TypicalNoise::Density(density));
+            }
+            Mode::ContinuousLowPower1 | Mode::SingleConversionLowPower1 => (4.5, 3.2),
+            Mode::ContinuousLowPower2 | Mode::SingleConversionLowPower2 => (2.4, 1.8),
+            Mode::ContinuousLowPower3 | Mode::SingleConversionLowPower3 => (2.1, 1.5),
+            Mode::ContinuousLowPower4 | Mode::SingleConversionLowPower4 => (1.3, 1.0),
+        };
+        Some(TypicalNoise::Rms(if self.low_noise {
+            low_noise
+        } else {
+            normal
+        }))
+    }
+}
@@ -0,0 +1,69 @@
+use crate::{AccelerationData, TiltReference};
+
+/// High-pass filter reference mode
+///
+/// In reference mode the device subtracts a reference acceleration, latched when the mode is enabled,
+/// from the output data and the wake-up detection. The driver keeps a copy of the reference to compute
+/// the tilt relative to it.
+///
+/// Created by [`Lis2dtw12::enable_reference_mode`](crate::Lis2dtw12::enable_reference_mode)
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReferenceMode {
+    pub(crate) reference: AccelerationData,
+}
+
+/// Deviation from the reference acceleration
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct ReferenceDeviation {
+    /// Deviation from the reference in **mg**
+    pub deviation: AccelerationData,
+    /// Magnitude of the deviation in **mg**
+    pub magnitude: f32,
+    /// Angle between the reference and the current acceleration in degrees
+    pub tilt_deg: f32,
+}
+
+impl ReferenceMode {
+    /// Get the reference acceleration in **mg**
+    pub fn reference(&self) -> AccelerationData {
+        self.reference
+    }
+
+    /// Get the deviation from the reference for an output sample taken in reference mode
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `deviation`: Acceleration data in **mg** read in reference mode
+    pub fn deviation(&self, deviation: AccelerationData) -> ReferenceDeviation {
+        let current = AccelerationData {
+            x: self.reference.x + deviation.x,
+            y: self.reference.y + deviation.y,
+            z: self.reference.z + deviation.z,
+        };
+        let dot = self.reference.x * current.x
+            + self.reference.y * current.y
+            + self.reference.z * current.z;
+        let norm = self.reference.magnitude() * current.magnitude();
+        let tilt_deg = if norm > 0.0 {
+            libm::acosf((dot / norm).clamp(-1.0, 1.0)).to_degrees()
+        } else {
+            0.0
+        };
+
+        ReferenceDeviation {
+            deviation,
+            magnitude: deviation.magnitude(),
+            tilt_deg,
+        }
+    }
+
+    /// Get the deviation magnitude in **mg** caused by tilting the reference by the given angle
+    ///
+    /// Can be used to set the wake-up threshold for a tilt angle
+    /// (see [`Lis2dtw12::set_reference_wake_up_threshold`](crate::Lis2dtw12::set_reference_wake_up_threshold))
+    pub fn tilt_to_mg(&self, tilt_deg: f32) -> f32 {
+        TiltReference::tilt_to_mg(self.reference.magnitude(), tilt_deg)
+    }
+}
@@ -0,0 +1,544 @@
+pub use crate::registers::Register;
+use crate::registers::*;
+
+/// Typed value of a register
+///
+/// Each register struct wraps the register value and provides getters and `with_*` builders
+/// for the fields, multi-bit fields are right-aligned (e.g. [`Ctrl1::odr`] is the 4-bit ODR value).
+/// Use them with [`Lis2dtw12::read_register`](crate::Lis2dtw12::read_register),
+/// [`Lis2dtw12::write_register`](crate::Lis2dtw12::write_register) and
+/// [`Lis2dtw12::modify_register`](crate::Lis2dtw12::modify_register).
+pub trait RegisterValue: Copy + From<u8> + Into<u8> {
+    /// Register the value belongs to
+    const REGISTER: Register;
+}
+
+/// Marker for registers that can be written
+pub trait WritableRegister: RegisterValue {}
+
+macro_rules! register {
+    (
+        $(#[$meta:meta])*
+        $name:ident: $reg:ident, $access:ident {
+            $(
+                $(#[$field_meta:meta])*
+                $field:ident, $with_field:ident: $kind:ident = $mask:expr;
+            )*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+        #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+        pub struct $name(pub u8);
+
+        impl $name {
+            $(
+                register!(@field $(#[$field_meta])* $field, $with_field, $kind, $mask);
+            )*
+        }
+
+        impl From<u8> for $name {
+            fn from(value: u8) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl RegisterValue for $name {
+            const REGISTER: Register = Register::$reg;
+        }
+
+        register!(@access $name, $access);
+    };
+    (@access $name:ident, read_write) => {
+        impl WritableRegister for $name {}
+    };
+    (@access $name:ident, read_only) => {};
+    (@field $(#[$meta:meta])* $field:ident, $with_field:ident, flag, $mask:expr) => {
+        $(#[$meta])*
+        pub fn $field(&self) -> bool {
+            self.0 & $mask != 0
+        }
+
+        $(#[$meta])*
+        #[must_use]
+        pub fn $with_field(self, value: bool) -> Self {
+            if value {
+                Self(self.0 | $mask)
+            } else {
+                Self(self.0 & !$mask)
+            }
+        }
+    };
+    (@field $(#[$meta:meta])* $field:ident, $with_field:ident, bits, $mask:expr) => {
+        $(#[$meta])*
+        pub fn $field(&self) -> u8 {
+            (self.0 & $mask) >> ($mask as u8).trailing_zeros()
+        }
+
+        $(#[$meta])*
+        ///
+        /// Bits not fitting into the field are ignored
+        #[must_use]
+        pub fn $with_field(self, value: u8) -> Self {
+            Self(self.0 & !$mask | (value << ($mask as u8).trailing_zeros()) & $mask)
+        }
+    };
+}
+
+register! {
+    /// OUT_T_L: Temperature output, low byte (bits 7:4 hold the 4 LSB of the 12-bit value)
+    OutTL: OUT_T_L, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// OUT_T_H: Temperature output, high byte
+    OutTH: OUT_T_H, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// WHO_AM_I: Device identification (0x44)
+    WhoAmI: WHO_AM_I, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// CTRL1: Output data rate and mode selection
+    Ctrl1: CTRL1, read_write {
+        /// Output data rate selection (ODR\[3:0\])
+        odr, with_odr: bits = ODR_MASK;
+        /// Mode selection (MODE\[1:0\])
+        mode, with_mode: bits = MODE_MASK;
+        /// Low-power mode selection (LP_MODE\[1:0\])
+        lp_mode, with_lp_mode: bits = LP_MODE_MASK;
+    }
+}
+
+register! {
+    /// CTRL2: Boot, reset and interface settings
+    Ctrl2: CTRL2, read_write {
+        /// Reboot memory content (BOOT)
+        boot, with_boot: flag = BOOT;
+        /// Soft reset (SOFT_RESET)
+        soft_reset, with_soft_reset: flag = SOFT_RESET;
+        /// Disconnect the CS pull-up (CS_PU_DISC)
+        cs_pu_disc, with_cs_pu_disc: flag = CS_PU_DISC;
+        /// Block data update (BDU)
+        bdu, with_bdu: flag = BDU;
+        /// Register address auto-increment on multiple byte access (IF_ADD_INC)
+        if_add_inc, with_if_add_inc: flag = IF_ADD_INC;
+        /// Disable the I2C interface (I2C_DISABLE)
+        i2c_disable, with_i2c_disable: flag = I2C_DISABLE;
+        /// SPI serial interface mode, 3-wire when set (SIM)
+        sim, with_sim: flag = SIM;
+    }
+}
+
+register! {
+    /// CTRL3: Self-test, interrupt pad and single data conversion settings
+    Ctrl3: CTRL3, read_write {
+        /// Self-test enable (ST\[1:0\])
+        st, with_st: bits = ST_MASK;
+        /// Open-drain interrupt pads (PP_OD)
+        pp_od, with_pp_od: flag = PP_OD;
+        /// Latched interrupts (LIR)
+        lir, with_lir: flag = LIR;
+        /// Active low interrupt pads (H_LACTIVE)
+        h_lactive, with_h_lactive: flag = H_LACTIVE;
+        /// Single data conversion triggered by the INT2 pin instead of SLP_MODE_1 (SLP_MODE_SEL)
+        slp_mode_sel, with_slp_mode_sel: flag = SLP_MODE_SEL;
+        /// Start a single data conversion (SLP_MODE_1)
+        slp_mode_1, with_slp_mode_1: flag = SLP_MODE_1;
+    }
+}
+
+register! {
+    /// CTRL4_INT1_PAD_CTRL: INT1 pad routing
+    Ctrl4Int1PadCtrl: CTRL4_INT1_PAD_CTRL, read_write {
+        /// 6D recognition on INT1 (INT1_6D)
+        int1_6d, with_int1_6d: flag = INT1_6D;
+        /// Single-tap recognition on INT1 (INT1_SINGLE_TAP)
+        int1_single_tap, with_int1_single_tap: flag = INT1_SINGLE_TAP;
+        /// Wake-up recognition on INT1 (INT1_WU)
+        int1_wu, with_int1_wu: flag = INT1_WU;
+        /// Free-fall recognition on INT1 (INT1_FF)
+        int1_ff, with_int1_ff: flag = INT1_FF;
+        /// Double-tap recognition on INT1 (INT1_TAP)
+        int1_tap, with_int1_tap: flag = INT1_TAP;
+        /// FIFO full recognition on INT1 (INT1_DIFF5)
+        int1_diff5, with_int1_diff5: flag = INT1_DIFF5;
+        /// FIFO threshold on INT1 (INT1_FTH)
+        int1_fth, with_int1_fth: flag = INT1_FTH;
+        /// Data-ready on INT1 (INT1_DRDY)
+        int1_drdy, with_int1_drdy: flag = INT1_DRDY;
+    }
+}
+
+register! {
+    /// CTRL5_INT2_PAD_CTRL: INT2 pad routing
+    Ctrl5Int2PadCtrl: CTRL5_INT2_PAD_CTRL, read_write {
+        /// Sleep state on INT2 (INT2_SLEEP_STATE)
+        int2_sleep_state, with_int2_sleep_state: flag = INT2_SLEEP_STATE;
+        /// Sleep change status on INT2 (INT2_SLEEP_CHG)
+        int2_sleep_chg, with_int2_sleep_chg: flag = INT2_SLEEP_CHG;
+        /// Boot state on INT2 (INT2_BOOT)
+        int2_boot, with_int2_boot: flag = INT2_BOOT;
+        /// Temperature data-ready on INT2 (INT2_DRDY_T)
+        int2_drdy_t, with_int2_drdy_t: flag = INT2_DRDY_T;
+        /// FIFO overrun on INT2 (INT2_OVR)
+        int2_ovr, with_int2_ovr: flag = INT2_OVR;
+        /// FIFO full recognition on INT2 (INT2_DIFF5)
+        int2_diff5, with_int2_diff5: flag = INT2_DIFF5;
+        /// FIFO threshold on INT2 (INT2_FTH)
+        int2_fth, with_int2_fth: flag = INT2_FTH;
+        /// Data-ready on INT2 (INT2_DRDY)
+        int2_drdy, with_int2_drdy: flag = INT2_DRDY;
+    }
+}
+
+register! {
+    /// CTRL6: Bandwidth, full-scale and filter settings
+    Ctrl6: CTRL6, read_write {
+        /// Bandwidth selection (BW_FILT\[1:0\])
+        bw_filt, with_bw_filt: bits = BW_FILT_MASK;
+        /// Full-scale selection (FS\[1:0\])
+        fs, with_fs: bits = FS_MASK;
+        /// Filtered data type selection, high-pass when set (FDS)
+        fds, with_fds: flag = FDS;
+        /// Low-noise configuration (LOW_NOISE)
+        low_noise, with_low_noise: flag = LOW_NOISE;
+    }
+}
+
+register! {
+    /// STATUS: Event status
+    StatusReg: STATUS, read_only {
+        /// FIFO threshold reached (FIFO_THS)
+        fifo_ths, with_fifo_ths: flag = FIFO_THS;
+        /// Wake-up event detected (WU_IA)
+        wu_ia, with_wu_ia: flag = WU_IA;
+        /// Sleep event status (SLEEP_STATE)
+        sleep_state, with_sleep_state: flag = SLEEP_STATE;
+        /// Double-tap event detected (DOUBLE_TAP)
+        double_tap, with_double_tap: flag = DOUBLE_TAP;
+        /// Single-tap event detected (SINGLE_TAP)
+        single_tap, with_single_tap: flag = SINGLE_TAP;
+        /// Change in position detected (6D_IA)
+        d6d_ia, with_d6d_ia: flag = D6D_IA;
+        /// Free-fall event detected (FF_IA)
+        ff_ia, with_ff_ia: flag = FF_IA;
+        /// Data-ready (DRDY)
+        drdy, with_drdy: flag = DRDY;
+    }
+}
+
+register! {
+    /// OUT_X_L: X-axis acceleration output, low byte
+    OutXL: OUT_X_L, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// OUT_X_H: X-axis acceleration output, high byte
+    OutXH: OUT_X_H, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// OUT_Y_L: Y-axis acceleration output, low byte
+    OutYL: OUT_Y_L, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// OUT_Y_H: Y-axis acceleration output, high byte
+    OutYH: OUT_Y_H, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// OUT_Z_L: Z-axis acceleration output, low byte
+    OutZL: OUT_Z_L, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// OUT_Z_H: Z-axis acceleration output, high byte
+    OutZH: OUT_Z_H, read_only {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// FIFO_CTRL: FIFO mode and threshold
+    FifoCtrl: FIFO_CTRL, read_write {
+        /// FIFO mode selection (FMODE\[2:0\])
+        fmode, with_fmode: bits = FMODE_MASK;
+        /// FIFO threshold level (FTH\[4:0\])
+        fth, with_fth: bits = FTH_MASK;
+    }
+}
+
+register! {
+    /// FIFO_SAMPLES: FIFO status
+    FifoSamples: FIFO_SAMPLES, read_only {
+        /// FIFO threshold reached (FIFO_FTH)
+        fifo_fth, with_fifo_fth: flag = FIFO_FTH;
+        /// FIFO overrun (FIFO_OVR)
+        fifo_ovr, with_fifo_ovr: flag = FIFO_OVR;
+        /// Number of unread samples in the FIFO (DIFF\[5:0\])
+        diff, with_diff: bits = FIFO_DIFF;
+    }
+}
+
+register! {
+    /// TAP_THS_X: 4D/6D settings and X-axis tap threshold
+    TapThsX: TAP_THS_X, read_write {
+        /// 4D detection enable (4D_EN)
+        en_4d, with_en_4d: flag = EN_4D;
+        /// 6D threshold (6D_THS\[1:0\])
+        ths_6d, with_ths_6d: bits = THS_6D_MASK;
+        /// X-axis tap threshold (TAP_THSX\[4:0\])
+        tap_thsx, with_tap_thsx: bits = TAP_THS_MASK;
+    }
+}
+
+register! {
+    /// TAP_THS_Y: Tap axis priority and Y-axis tap threshold
+    TapThsY: TAP_THS_Y, read_write {
+        /// Axis priority for tap detection (TAP_PRIOR\[2:0\])
+        tap_prior, with_tap_prior: bits = TAP_PRIOR_MASK;
+        /// Y-axis tap threshold (TAP_THSY\[4:0\])
+        tap_thsy, with_tap_thsy: bits = TAP_THS_MASK;
+    }
+}
+
+register! {
+    /// TAP_THS_Z: Tap axis enables and Z-axis tap threshold
+    TapThsZ: TAP_THS_Z, read_write {
+        /// X-axis tap recognition enable (TAP_X_EN)
+        tap_x_en, with_tap_x_en: flag = TAP_X_EN;
+        /// Y-axis tap recognition enable (TAP_Y_EN)
+        tap_y_en, with_tap_y_en: flag = TAP_Y_EN;
+        /// Z-axis tap recognition enable (TAP_Z_EN)
+        tap_z_en, with_tap_z_en: flag = TAP_Z_EN;
+        /// Z-axis tap threshold (TAP_THSZ\[4:0\])
+        tap_thsz, with_tap_thsz: bits = TAP_THS_MASK;
+    }
+}
+
+register! {
+    /// INT_DUR: Tap recognition timings
+    IntDur: INT_DUR, read_write {
+        /// Maximum time between two taps of a double-tap (LATENCY\[3:0\])
+        latency, with_latency: bits = LATENCY_MASK;
+        /// Quiet time after a tap (QUIET\[1:0\])
+        quiet, with_quiet: bits = QUIET_MASK;
+        /// Maximum duration of a tap (SHOCK\[1:0\])
+        shock, with_shock: bits = SHOCK_MASK;
+    }
+}
+
+register! {
+    /// WAKE_UP_THS: Tap mode, sleep enable and wake-up threshold
+    WakeUpThs: WAKE_UP_THS, read_write {
+        /// Double-tap recognition enable (SINGLE_DOUBLE_TAP)
+        single_double_tap, with_single_double_tap: flag = SINGLE_DOUBLE_TAP;
+        /// Sleep (inactivity) enable (SLEEP_ON)
+        sleep_on, with_sleep_on: flag = SLEEP_ON;
+        /// Wake-up threshold, 1 LSB = FS / 64 (WK_THS\[5:0\])
+        wk_ths, with_wk_ths: bits = WK_THS_MASK;
+    }
+}
+
+register! {
+    /// WAKE_UP_DUR: Wake-up and sleep durations
+    WakeUpDur: WAKE_UP_DUR, read_write {
+        /// Free-fall duration bit 5 (FF_DUR5)
+        ff_dur5, with_ff_dur5: flag = FF_DUR5;
+        /// Wake-up duration, 1 LSB = 1 / ODR (WAKE_DUR\[1:0\])
+        wake_dur, with_wake_dur: bits = WK_DUR_MASK;
+        /// Stationary detection enable (STATIONARY)
+        stationary, with_stationary: flag = STATIONARY;
+        /// Duration to go to sleep, 1 LSB = 512 / ODR (SLEEP_DUR\[3:0\])
+        sleep_dur, with_sleep_dur: bits = SLEEP_DUR_MASK;
+    }
+}
+
+register! {
+    /// FREE_FALL: Free-fall duration and threshold
+    FreeFall: FREE_FALL, read_write {
+        /// Free-fall duration bits 4:0, 1 LSB = 1 / ODR (FF_DUR\[4:0\])
+        ff_dur, with_ff_dur: bits = FF_DUR_MASK;
+        /// Free-fall threshold (FF_THS\[2:0\])
+        ff_ths, with_ff_ths: bits = FF_THS_MASK;
+    }
+}
+
+register! {
+    /// STATUS_DUP: Event status (duplicate with overrun and temperature data-ready)
+    StatusDup: STATUS_DUP, read_only {
+        /// FIFO overrun (OVR)
+        ovr, with_ovr: flag = OVR;
+        /// Temperature data-ready (DRDY_T)
+        drdy_t, with_drdy_t: flag = DRDY_T;
+        /// Sleep change event (SLEEP_STATE_IA)
+        sleep_state_ia, with_sleep_state_ia: flag = SLEEP_STATE_IA;
+        /// Double-tap event detected (DOUBLE_TAP)
+        double_tap, with_double_tap: flag = DOUBLE_TAP;
+        /// Single-tap event detected (SINGLE_TAP)
+        single_tap, with_single_tap: flag = SINGLE_TAP;
+        /// Change in position detected (6D_IA)
+        d6d_ia, with_d6d_ia: flag = D6D_IA;
+        /// Free-fall event detected (FF_IA)
+        ff_ia, with_ff_ia: flag = FF_IA;
+        /// Data-ready (DRDY)
+        drdy, with_drdy: flag = DRDY;
+    }
+}
+
+register! {
+    /// WAKE_UP_SRC: Wake-up source
+    WakeUpSrc: WAKE_UP_SRC, read_only {
+        /// Free-fall event detected (FF_IA)
+        ff_ia, with_ff_ia: flag = WAKE_UP_FF_IA;
+        /// Sleep event status (SLEEP_STATE_IA)
+        sleep_state_ia, with_sleep_state_ia: flag = WAKE_UP_SLEEP_STATE_IA;
+        /// Wake-up event detected (WU_IA)
+        wu_ia, with_wu_ia: flag = WAKE_UP_WU_IA;
+        /// Wake-up on the X-axis (X_WU)
+        x_wu, with_x_wu: flag = X_WU;
+        /// Wake-up on the Y-axis (Y_WU)
+        y_wu, with_y_wu: flag = Y_WU;
+        /// Wake-up on the Z-axis (Z_WU)
+        z_wu, with_z_wu: flag = Z_WU;
+    }
+}
+
+register! {
+    /// TAP_SRC: Tap source
+    TapSrc: TAP_SRC, read_only {
+        /// Tap event detected (TAP_IA)
+        tap_ia, with_tap_ia: flag = TAP_IA;
+        /// Single-tap event detected (SINGLE_TAP)
+        single_tap, with_single_tap: flag = TAP_SRC_SINGLE_TAP;
+        /// Double-tap event detected (DOUBLE_TAP)
+        double_tap, with_double_tap: flag = TAP_SRC_DOUBLE_TAP;
+        /// Sign of the acceleration of the tap, negative when set (TAP_SIGN)
+        tap_sign, with_tap_sign: flag = TAP_SIGN;
+        /// Tap on the X-axis (X_TAP)
+        x_tap, with_x_tap: flag = X_TAP;
+        /// Tap on the Y-axis (Y_TAP)
+        y_tap, with_y_tap: flag = Y_TAP;
+        /// Tap on the Z-axis (Z_TAP)
+        z_tap, with_z_tap: flag = Z_TAP;
+    }
+}
+
+register! {
+    /// SIXD_SRC: 6D source
+    SixdSrc: SIXD_SRC, read_only {
+        /// Change in position detected (6D_IA)
+        ia_6d, with_ia_6d: flag = IA_6D;
+        /// Z-axis high event (ZH)
+        zh, with_zh: flag = ZH;
+        /// Z-axis low event (ZL)
+        zl, with_zl: flag = ZL;
+        /// Y-axis high event (YH)
+        yh, with_yh: flag = YH;
+        /// Y-axis low event (YL)
+        yl, with_yl: flag = YL;
+        /// X-axis high event (XH)
+        xh, with_xh: flag = XH;
+        /// X-axis low event (XL)
+        xl, with_xl: flag = XL;
+    }
+}
+
+register! {
+    /// ALL_INT_SRC: All interrupt sources
+    AllIntSrc: ALL_INT_SRC, read_only {
+        /// Sleep change event (SLEEP_CHANGE_IA)
+        sleep_change_ia, with_sleep_change_ia: flag = ALL_INT_SLEEP_CHANGE_IA;
+        /// Change in position detected (6D_IA)
+        ia_6d, with_ia_6d: flag = ALL_INT_6D_IA;
+        /// Double-tap event detected (DOUBLE_TAP)
+        double_tap, with_double_tap: flag = ALL_INT_DOUBLE_TAP;
+        /// Single-tap event detected (SINGLE_TAP)
+        single_tap, with_single_tap: flag = ALL_INT_SINGLE_TAP;
+        /// Wake-up event detected (WU_IA)
+        wu_ia, with_wu_ia: flag = ALL_INT_WU_IA;
+        /// Free-fall event detected (FF_IA)
+        ff_ia, with_ff_ia: flag = ALL_INT_FF_IA;
+    }
+}
+
+register! {
+    /// X_OFS_USR: X-axis user offset (two's complement)
+    XOfsUsr: X_OFS_USR, read_write {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// Y_OFS_USR: Y-axis user offset (two's complement)
+    YOfsUsr: Y_OFS_USR, read_write {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// Z_OFS_USR: Z-axis user offset (two's complement)
+    ZOfsUsr: Z_OFS_USR, read_write {
+        /// Register value
+        value, with_value: bits = 0b1111_1111;
+    }
+}
+
+register! {
+    /// CTRL7: Interrupt and user offset settings
+    Ctrl7: CTRL7, read_write {
+        /// Pulsed data-ready mode (DRDY_PULSED)
+        drdy_pulsed, with_drdy_pulsed: flag = DRDY_PULSED;
+        /// Route all INT2 signals to INT1 (INT2_ON_INT1)
+        int2_on_int1, with_int2_on_int1: flag = INT2_ON_INT1;
+        /// Enable the interrupts (INTERRUPTS_ENABLE)
+        interrupts_enable, with_interrupts_enable: flag = INTERRUPTS_ENABLE;
+        /// Apply the user offset to the output data (USR_OFF_ON_OUT)
+        usr_off_on_out, with_usr_off_on_out: flag = USR_OFF_ON_OUT;
+        /// Apply the user offset to the wake-up function (USR_OFF_ON_WU)
+        usr_off_on_wu, with_usr_off_on_wu: flag = USR_OFF_ON_WU;
+        /// Weight of the user offset, 15.6 mg/LSB when set, 977 µg/LSB otherwise (USR_OFF_W)
+        usr_off_w, with_usr_off_w: flag = USR_OFF_W;
+        /// High-pass filter reference mode (HP_REF_MODE)
+        hp_ref_mode, with_hp_ref_mode: flag = HP_REF_MODE;
+        /// Low-pass filtered data sent to the 6D interrupt function (LPASS_ON6D)
+        lpass_on6d, with_lpass_on6d: flag = LPASS_ON6D;
+    }
+}
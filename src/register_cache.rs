@@ -0,0 +1,216 @@
+use crate::registers::{Register, BOOT, SLP_MODE_1, SOFT_RESET};
+
+/// Address of the first cacheable register (CTRL1)
+const FIRST_ADDR: u8 = 0x20;
+
+/// Number of addresses covered by the cache (CTRL1 to CTRL7)
+const SIZE: usize = 32;
+
+/// Blocks of consecutive control registers, read on a refresh
+pub(crate) const BLOCKS: [(Register, usize); 4] = [
+    (Register::CTRL1, 6),
+    (Register::FIFO_CTRL, 1),
+    (Register::TAP_THS_X, 7),
+    (Register::X_OFS_USR, 4),
+];
+
+/// Largest block in [`BLOCKS`]
+pub(crate) const MAX_BLOCK_LEN: usize = 7;
+
+/// Write-through cache of the control registers
+///
+/// Status and output registers are never cached. Self-clearing bits (BOOT, SOFT_RESET and
+/// SLP_MODE_1) are not stored, so writing them always reaches the device.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub(crate) struct RegisterCache {
+    enabled: bool,
+    valid: u32,
+    values: [u8; SIZE],
+}
+
+impl RegisterCache {
+    pub(crate) const fn new() -> Self {
+        Self {
+            enabled: false,
+            valid: 0,
+            values: [0; SIZE],
+        }
+    }
+
+    pub(crate) fn set_enabled(&mut self, enabled: bool) {
+        self.enabled = enabled;
+        self.invalidate();
+    }
+
+    pub(crate) fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub(crate) fn invalidate(&mut self) {
+        self.valid = 0;
+    }
+
+    /// Get the cached value of a register, if valid
+    pub(crate) fn get(&self, reg: Register) -> Option<u8> {
+        let index = Self::index(reg)?;
+        (self.enabled && self.valid & (1 << index) != 0).then_some(self.values[index])
+    }
+
+    /// Update the cache after a successful write
+    pub(crate) fn update(&mut self, reg: Register, value: u8) {
+        if matches!(reg, Register::CTRL2) && value & (BOOT | SOFT_RESET) != 0 {
+            // The device reloads its registers
+            self.invalidate();
+            return;
+        }
+        self.store(reg.addr(), value);
+    }
+
+    /// Update the cache with a block of values read from the device
+    pub(crate) fn fill(&mut self, first: Register, values: &[u8]) {
+        for (addr, value) in (first.addr()..).zip(values) {
+            self.store(addr, *value);
+        }
+    }
+
+    /// Drop a register from the cache (e.g. after a failed write)
+    pub(crate) fn remove(&mut self, reg: Register) {
+        if let Some(index) = Self::index(reg) {
+            self.valid &= !(1 << index);
+        }
+    }
+
+    fn store(&mut self, addr: u8, value: u8) {
+        if !self.enabled {
+            return;
+        }
+        let self_clearing = if addr == Register::CTRL2.addr() {
+            BOOT | SOFT_RESET
+        } else if addr == Register::CTRL3.addr() {
+            SLP_MODE_1
+        } else {
+            0
+        };
+        let index = (addr - FIRST_ADDR) as usize;
+        self.values[index] = value & !self_clearing;
+        self.valid |= 1 << index;
+    }
+
+    fn index(reg: Register) -> Option<usize> {
+        match reg {
+            Register::CTRL1
+            | Register::CTRL2
+            | Register::CTRL3
+            | Register::CTRL4_INT1_PAD_CTRL
+            | Register::CTRL5_INT2_PAD_CTRL
+            | Register::CTRL6
+            | Register::FIFO_CTRL
+            | Register::TAP_THS_X
+            | Register::TAP_THS_Y
+            | Register::TAP_THS_Z
+            | Register::INT_DUR
+            | Register::WAKE_UP_THS
+            | Register::WAKE_UP_DUR
+            | Register::FREE_FALL
+            | Register::X_OFS_USR
+            | Register::Y_OFS_USR
+            | Register::Z_OFS_USR
+            | Register::CTRL7 => Some((reg.addr() - FIRST_ADDR) as usize),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::{BDU, LIR};
+
+    fn enabled() -> RegisterCache {
+        let mut cache = RegisterCache::new();
+        cache.set_enabled(true);
+        cache
+    }
+
+    #[test]
+    fn disabled_cache_stores_nothing() {
+        let mut cache = RegisterCache::new();
+        cache.update(Register::CTRL1, 0x50);
+        ::core::assert_eq!(cache.get(Register::CTRL1), None);
+    }
+
+    #[test]
+    fn update_and_get() {
+        let mut cache = enabled();
+        ::core::assert_eq!(cache.get(Register::CTRL1), None);
+        cache.update(Register::CTRL1, 0x50);
+        cache.update(Register::CTRL7, 0x20);
+        ::core::assert_eq!(cache.get(Register::CTRL1), Some(0x50));
+        ::core::assert_eq!(cache.get(Register::CTRL7), Some(0x20));
+        ::core::assert_eq!(cache.get(Register::CTRL6), None);
+    }
+
+    #[test]
+    fn status_registers_not_cached() {
+        let mut cache = enabled();
+        cache.update(Register::STATUS, 0x01);
+        cache.update(Register::WAKE_UP_SRC, 0x08);
+        ::core::assert_eq!(cache.get(Register::STATUS), None);
+        ::core::assert_eq!(cache.get(Register::WAKE_UP_SRC), None);
+    }
+
+    #[test]
+    fn slp_mode_1_not_stored() {
+        let mut cache = enabled();
+        cache.update(Register::CTRL3, LIR | SLP_MODE_1);
+        // The trigger must not look like it is already written
+        ::core::assert_eq!(cache.get(Register::CTRL3), Some(LIR));
+        ::core::assert_ne!(cache.get(Register::CTRL3), Some(LIR | SLP_MODE_1));
+    }
+
+    #[test]
+    fn reset_and_boot_invalidate() {
+        for bit in [SOFT_RESET, BOOT] {
+            let mut cache = enabled();
+            cache.update(Register::CTRL1, 0x50);
+            cache.update(Register::CTRL2, BDU);
+            ::core::assert_eq!(cache.get(Register::CTRL2), Some(BDU));
+
+            cache.update(Register::CTRL2, BDU | bit);
+            ::core::assert_eq!(cache.get(Register::CTRL1), None);
+            ::core::assert_eq!(cache.get(Register::CTRL2), None);
+        }
+    }
+
+    #[test]
+    fn fill_strips_self_clearing_bits() {
+        let mut cache = enabled();
+        // CTRL1 to CTRL6, read while a boot and a single conversion are pending
+        cache.fill(
+            Register::CTRL1,
+            &[0x50, BDU | BOOT, LIR | SLP_MODE_1, 0x01, 0x02, 0x04],
+        );
+        ::core::assert_eq!(cache.get(Register::CTRL1), Some(0x50));
+        ::core::assert_eq!(cache.get(Register::CTRL2), Some(BDU));
+        ::core::assert_eq!(cache.get(Register::CTRL3), Some(LIR));
+        ::core::assert_eq!(cache.get(Register::CTRL6), Some(0x04));
+    }
+
+    #[test]
+    fn remove_and_invalidate() {
+        let mut cache = enabled();
+        cache.update(Register::CTRL1, 0x50);
+        cache.update(Register::CTRL6, 0x04);
+        cache.remove(Register::CTRL1);
+        ::core::assert_eq!(cache.get(Register::CTRL1), None);
+        ::core::assert_eq!(cache.get(Register::CTRL6), Some(0x04));
+
+        cache.invalidate();
+        ::core::assert_eq!(cache.get(Register::CTRL6), None);
+
+        cache.update(Register::CTRL6, 0x04);
+        cache.set_enabled(true);
+        ::core::assert_eq!(cache.get(Register::CTRL6), None);
+    }
+}
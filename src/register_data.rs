@@ -1,4 +1,5 @@
 use crate::registers::*;
+use crate::{FullScale, Mode};
 
 /// Struct representation of the Status register
 #[derive(Debug, Copy, Clone)]
@@ -91,6 +92,12 @@ pub struct EventStatus {
     ///
     /// true: sleep event detected
     pub sleep_event: bool,
+    /// Sleep change event status (SLEEP_STATE_IA)
+    ///
+    /// false: no change of the sleep state detected
+    ///
+    /// true: the device entered or left the sleep state
+    pub sleep_change_event: bool,
     /// Double-tap event status
     ///
     /// false: no tap event detected
@@ -129,6 +136,7 @@ impl From<u8> for EventStatus {
             fifo_overrun: value & OVR != 0,
             temperature_data_ready: value & DRDY_T != 0,
             sleep_event: value & SLEEP_STATE_IA != 0,
+            sleep_change_event: value & SLEEP_STATE_IA != 0,
             double_tap_event: value & DOUBLE_TAP != 0,
             single_tap_event: value & SINGLE_TAP != 0,
             position_change_event: value & D6D_IA != 0,
@@ -138,8 +146,29 @@ impl From<u8> for EventStatus {
     }
 }
 
+/// Sleep status
+///
+/// Combines the sleep state (level) of the STATUS register with the
+/// sleep change event (edge) of the STATUS_DUP register
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SleepStatus {
+    /// Sleep state (level)
+    ///
+    /// false: device is awake (activity)
+    ///
+    /// true: device is in sleep state (inactivity)
+    pub sleeping: bool,
+    /// Sleep change event (edge)
+    ///
+    /// false: no change of the sleep state detected
+    ///
+    /// true: device entered or left the sleep state
+    pub sleep_change_event: bool,
+}
+
 /// Acceleration data
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct AccelerationData {
     /// X-axis acceleration
@@ -150,8 +179,19 @@ pub struct AccelerationData {
     pub z: f32,
 }
 
+impl AccelerationData {
+    /// Compute the magnitude of the acceleration vector
+    ///
+    /// # Returns
+    ///
+    /// - Magnitude in the same unit as the data (usually **mg**)
+    pub fn magnitude(&self) -> f32 {
+        libm::sqrtf(self.x * self.x + self.y * self.y + self.z * self.z)
+    }
+}
+
 /// RAW acceleration data
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub struct RawAccelerationData {
     /// X-axis acceleration
@@ -162,6 +202,47 @@ pub struct RawAccelerationData {
     pub z: i16,
 }
 
+impl RawAccelerationData {
+    /// Convert the RAW acceleration data to **mg**
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `full_scale`: Full-scale the data was sampled with
+    /// - `mode`: Mode the data was sampled with (determines the resolution)
+    pub fn to_mg(&self, full_scale: FullScale, mode: Mode) -> AccelerationData {
+        AccelerationData {
+            x: full_scale.convert_raw_i16_to_mg(self.x, mode),
+            y: full_scale.convert_raw_i16_to_mg(self.y, mode),
+            z: full_scale.convert_raw_i16_to_mg(self.z, mode),
+        }
+    }
+}
+
+/// Acceleration sample read together with the Status register in a single transaction
+///
+/// See [`Lis2dtw12::read_sample_with_status`](crate::Lis2dtw12::read_sample_with_status)
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SampleWithStatus {
+    /// Status register at the time the sample was read
+    pub status: Status,
+    /// RAW acceleration data
+    pub raw: RawAccelerationData,
+    /// Acceleration data in **mg**
+    pub data: AccelerationData,
+}
+
+impl SampleWithStatus {
+    /// Check if the sample is new (data ready was set when it was read)
+    ///
+    /// false: the sample was already read before
+    ///
+    /// true: X-, Y- and Z-axis new data
+    pub fn data_ready(&self) -> bool {
+        self.status.data_ready
+    }
+}
+
 /// FIFO Samples Status
 #[derive(Debug, Copy, Clone)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
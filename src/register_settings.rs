@@ -1,12 +1,12 @@
 use crate::{
-    INT1_6D, INT1_DIFF5, INT1_DRDY, INT1_FF, INT1_FTH, INT1_SINGLE_TAP, INT1_TAP, INT1_WU,
-    INT2_BOOT, INT2_DIFF5, INT2_DRDY, INT2_DRDY_T, INT2_FTH, INT2_OVR, INT2_SLEEP_CHG,
-    INT2_SLEEP_STATE,
+    AccelerationData, InvalidValue, RawAccelerationData, INT1_6D, INT1_DIFF5, INT1_DRDY, INT1_FF,
+    INT1_FTH, INT1_SINGLE_TAP, INT1_TAP, INT1_WU, INT2_BOOT, INT2_DIFF5, INT2_DRDY, INT2_DRDY_T,
+    INT2_FTH, INT2_OVR, INT2_SLEEP_CHG, INT2_SLEEP_STATE,
 };
 
 /// Operating Mode
 /// See the [datasheet](https://www.st.com/resource/en/datasheet/lis2dtw12.pdf) section 3.2.1 (Operating modes) for more info
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Mode {
     /// High performance mode (14-bit resolution)
@@ -30,10 +30,32 @@ pub enum Mode {
     SingleConversionLowPower1 = 0b1000,
 }
 
+/// Decode the MODE\[1:0\] and LP_MODE\[1:0\] bits of CTRL1 (`MODE << 2 | LP_MODE`)
+///
+/// The LP_MODE bits are ignored in high-performance mode
+impl TryFrom<u8> for Mode {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b0100..=0b0111 => Ok(Mode::HighPerformance),
+            0b0011 => Ok(Mode::ContinuousLowPower4),
+            0b0010 => Ok(Mode::ContinuousLowPower3),
+            0b0001 => Ok(Mode::ContinuousLowPower2),
+            0b0000 => Ok(Mode::ContinuousLowPower1),
+            0b1011 => Ok(Mode::SingleConversionLowPower4),
+            0b1010 => Ok(Mode::SingleConversionLowPower3),
+            0b1001 => Ok(Mode::SingleConversionLowPower2),
+            0b1000 => Ok(Mode::SingleConversionLowPower1),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
 /// Output Data Rate
 ///
 /// Rates are shown as: <High-performance rate> / <Low-power rate>
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum OutputDataRate {
     /// Power-down mode
@@ -59,8 +81,52 @@ pub enum OutputDataRate {
     Hz1600 = 0b1001,
 }
 
+impl TryFrom<u8> for OutputDataRate {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b0000 => Ok(OutputDataRate::PowerDown),
+            0b0001 => Ok(OutputDataRate::Hz1_6),
+            0b0010 => Ok(OutputDataRate::Hz12_5),
+            0b0011 => Ok(OutputDataRate::Hz25),
+            0b0100 => Ok(OutputDataRate::Hz50),
+            0b0101 => Ok(OutputDataRate::Hz100),
+            0b0110 => Ok(OutputDataRate::Hz200),
+            0b0111 => Ok(OutputDataRate::Hz400),
+            0b1000 => Ok(OutputDataRate::Hz800),
+            0b1001 => Ok(OutputDataRate::Hz1600),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
+impl OutputDataRate {
+    /// Get the effective output data rate in Hz for the given mode
+    ///
+    /// High-performance mode uses the first rate, the low-power modes use the second one
+    /// (see [`OutputDataRate`])
+    pub fn as_hz(self, mode: Mode) -> f32 {
+        let high_performance = matches!(mode, Mode::HighPerformance);
+        match self {
+            OutputDataRate::PowerDown => 0.0,
+            OutputDataRate::Hz1_6 if high_performance => 12.5,
+            OutputDataRate::Hz1_6 => 1.6,
+            OutputDataRate::Hz12_5 => 12.5,
+            OutputDataRate::Hz25 => 25.0,
+            OutputDataRate::Hz50 => 50.0,
+            OutputDataRate::Hz100 => 100.0,
+            OutputDataRate::Hz200 => 200.0,
+            OutputDataRate::Hz400 if high_performance => 400.0,
+            OutputDataRate::Hz800 if high_performance => 800.0,
+            OutputDataRate::Hz1600 if high_performance => 1600.0,
+            OutputDataRate::Hz400 | OutputDataRate::Hz800 | OutputDataRate::Hz1600 => 200.0,
+        }
+    }
+}
+
 /// Digital filtering cutoff selection / Bandwidth selection
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum BandwidthSelection {
     /// ODR/2 (up to ODR = 800 Hz, 400 Hz when ODR = 1600 Hz)
@@ -74,8 +140,34 @@ pub enum BandwidthSelection {
     OdrDiv20 = 0b11,
 }
 
+impl TryFrom<u8> for BandwidthSelection {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b00 => Ok(BandwidthSelection::OdrDiv2),
+            0b01 => Ok(BandwidthSelection::OdrDiv4),
+            0b10 => Ok(BandwidthSelection::OdrDiv10),
+            0b11 => Ok(BandwidthSelection::OdrDiv20),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
+impl BandwidthSelection {
+    /// Get the cutoff frequency in Hz for the given output data rate in Hz
+    pub fn cutoff_hz(self, odr_hz: f32) -> f32 {
+        match self {
+            BandwidthSelection::OdrDiv2 => (odr_hz / 2.0).min(400.0),
+            BandwidthSelection::OdrDiv4 => odr_hz / 4.0,
+            BandwidthSelection::OdrDiv10 => odr_hz / 10.0,
+            BandwidthSelection::OdrDiv20 => odr_hz / 20.0,
+        }
+    }
+}
+
 /// Full-scale selection
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum FullScale {
     /// ±2 g
@@ -89,7 +181,31 @@ pub enum FullScale {
     G16 = 0b11,
 }
 
+impl TryFrom<u8> for FullScale {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b00 => Ok(FullScale::G2),
+            0b01 => Ok(FullScale::G4),
+            0b10 => Ok(FullScale::G8),
+            0b11 => Ok(FullScale::G16),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
 impl FullScale {
+    /// Get the full-scale range in **mg**
+    pub(crate) fn range_mg(self) -> f32 {
+        match self {
+            FullScale::G2 => 2000.0,
+            FullScale::G4 => 4000.0,
+            FullScale::G8 => 8000.0,
+            FullScale::G16 => 16000.0,
+        }
+    }
+
     pub(crate) fn convert_raw_i16_to_mg(self, raw: i16, set_mode: Mode) -> f32 {
         // mg/digit
         let factor = match set_mode {
@@ -111,9 +227,43 @@ impl FullScale {
     }
 }
 
-/// Fifo Mode
+/// Format of the acceleration samples
+///
+/// Combination of the settings needed to interpret RAW samples, as cached by the driver
+/// (see [`Lis2dtw12::sample_format`](crate::Lis2dtw12::sample_format))
 #[derive(Debug, Copy, Clone, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SampleFormat {
+    /// Mode (determines the resolution)
+    pub mode: Mode,
+    /// Full-scale (determines the sensitivity)
+    pub full_scale: FullScale,
+    /// Output data rate
+    pub odr: OutputDataRate,
+    /// Bandwidth of the digital filter
+    pub bandwidth: BandwidthSelection,
+}
+
+impl SampleFormat {
+    /// Get the sample rate in Hz
+    pub fn sample_rate_hz(&self) -> f32 {
+        self.odr.as_hz(self.mode)
+    }
+
+    /// Get the -3 dB cutoff frequency of the digital low-pass filter in Hz
+    pub fn bandwidth_hz(&self) -> f32 {
+        self.bandwidth.cutoff_hz(self.sample_rate_hz())
+    }
+
+    /// Convert RAW acceleration data to **mg**
+    pub fn to_mg(&self, raw: &RawAccelerationData) -> AccelerationData {
+        raw.to_mg(self.full_scale, self.mode)
+    }
+}
+
+/// Fifo Mode
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum FifoMode {
     /// Bypass mode (FIFO turned off)
     #[default]
@@ -128,8 +278,23 @@ pub enum FifoMode {
     Continuous = 0b110,
 }
 
+impl TryFrom<u8> for FifoMode {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b000 => Ok(FifoMode::Bypass),
+            0b001 => Ok(FifoMode::StopOnFifoFull),
+            0b011 => Ok(FifoMode::ContinuousToFifo),
+            0b100 => Ok(FifoMode::BypassToContinuous),
+            0b110 => Ok(FifoMode::Continuous),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
 /// Thresholds for 4D/6D function @ FS = ±2 g
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Threshold6D {
     /// 6 (80°)
@@ -143,10 +308,24 @@ pub enum Threshold6D {
     Deg50 = 0b11,
 }
 
+impl TryFrom<u8> for Threshold6D {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b00 => Ok(Threshold6D::Deg80),
+            0b01 => Ok(Threshold6D::Deg70),
+            0b10 => Ok(Threshold6D::Deg60),
+            0b11 => Ok(Threshold6D::Deg50),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
 /// Tap Priority axis selection for tap detection
 ///
 /// MAX_PRIO, MID_PRIO, MIN_PRIO
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum TapPriority {
     /// X, Y, Z
@@ -168,8 +347,26 @@ pub enum TapPriority {
     ZYXAlt = 0b111,
 }
 
+impl TryFrom<u8> for TapPriority {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b000 => Ok(TapPriority::XYZ),
+            0b001 => Ok(TapPriority::YXZ),
+            0b010 => Ok(TapPriority::XZY),
+            0b011 => Ok(TapPriority::ZYX),
+            0b100 => Ok(TapPriority::XYZAlt),
+            0b101 => Ok(TapPriority::YZX),
+            0b110 => Ok(TapPriority::ZXY),
+            0b111 => Ok(TapPriority::ZYXAlt),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
 /// Free-fall Threshold @ FS = ±2 g
-#[derive(Debug, Copy, Clone, Default)]
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum FreeFallThreshold {
     /// 5
@@ -191,6 +388,37 @@ pub enum FreeFallThreshold {
     Ths16 = 0b111,
 }
 
+impl TryFrom<u8> for FreeFallThreshold {
+    type Error = InvalidValue;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0b000 => Ok(FreeFallThreshold::Ths5),
+            0b001 => Ok(FreeFallThreshold::Ths7),
+            0b010 => Ok(FreeFallThreshold::Ths8),
+            0b011 => Ok(FreeFallThreshold::Ths10),
+            0b100 => Ok(FreeFallThreshold::Ths11),
+            0b101 => Ok(FreeFallThreshold::Ths13),
+            0b110 => Ok(FreeFallThreshold::Ths15),
+            0b111 => Ok(FreeFallThreshold::Ths16),
+            _ => Err(InvalidValue(value)),
+        }
+    }
+}
+
+/// Sleep signalling on the INT2 pad
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SleepSignal {
+    /// No sleep signal on INT2
+    #[default]
+    Disabled,
+    /// Level: INT2 is active while the device is in sleep state (SLEEP_STATE)
+    State,
+    /// Edge: INT2 signals every change of the sleep state (SLEEP_CHANGE)
+    Change,
+}
+
 /// INT1 PAD Configuration
 #[derive(Debug, Copy, Clone, Default)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
@@ -1,9 +1,11 @@
 #![allow(non_upper_case_globals)]
 #![allow(dead_code)]
 
+/// Register addresses, named as in the datasheet
 #[allow(non_camel_case_types)]
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Copy, Clone)]
+#[allow(missing_docs)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[cfg_attr(feature = "defmt", derive(defmt::Format))]
 pub enum Register {
     OUT_T_L = 0x0D,
@@ -43,6 +45,7 @@ pub enum Register {
 }
 
 impl Register {
+    /// Get the address of the register
     pub fn addr(self) -> u8 {
         self as u8
     }
@@ -58,9 +61,12 @@ pub const LP_MODE_MASK: u8 = 0b0000_0011;
 pub const LP_MODE_SHIFT: u8 = 0;
 
 // ------- CTRL2 ------- //
+pub const BOOT: u8 = 0b1000_0000;
 pub const SOFT_RESET: u8 = 0b0100_0000;
 pub const CS_PU_DISC: u8 = 0b0001_0000;
 pub const BDU: u8 = 0b0000_1000;
+pub const IF_ADD_INC: u8 = 0b0000_0100;
+pub const I2C_DISABLE: u8 = 0b0000_0010;
 
 // ------- CTRL3 ------- //
 pub const ST_MASK: u8 = 0b1100_0000;
@@ -70,6 +76,7 @@ pub const LIR: u8 = 0b0001_0000;
 pub const H_LACTIVE: u8 = 0b0000_1000;
 pub const SLP_MODE_SEL: u8 = 0b0000_0010;
 pub const SIM: u8 = 0b0000_0001;
+pub const SLP_MODE_1: u8 = 0b0000_0001;
 
 // ------- CTRL4_INT1_PAD_CTRL ------- //
 pub const INT1_6D: u8 = 0b1000_0000;
@@ -136,6 +143,9 @@ pub const TAP_PRIOR_SHIFT: u8 = 5;
 // ------- TAP_THS_Z ------- //
 pub const TAP_XYZ_MASK: u8 = 0b1110_0000;
 pub const TAP_XYZ_SHIFT: u8 = 5;
+pub const TAP_X_EN: u8 = 0b1000_0000;
+pub const TAP_Y_EN: u8 = 0b0100_0000;
+pub const TAP_Z_EN: u8 = 0b0010_0000;
 
 // ------- INT_DUR ------- //
 pub const LATENCY_MASK: u8 = 0b1111_0000;
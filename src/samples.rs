@@ -0,0 +1,129 @@
+use embedded_hal::delay::DelayNs;
+
+use crate::registers::*;
+use crate::{AccelerationData, Error, FifoMode, FifoSamplesStatus, Interface, Lis2dtw12};
+
+/// Number of sample periods to wait for a new sample before reporting a timeout
+const TIMEOUT_SAMPLE_PERIODS: f32 = 2.0;
+
+/// Number of polls of the data-ready status per sample period
+const POLLS_PER_SAMPLE_PERIOD: f32 = 8.0;
+
+/// Iterator over new acceleration samples
+///
+/// Created by [`Lis2dtw12::samples`]
+pub struct Samples<'a, I: Interface, D: DelayNs> {
+    device: &'a mut Lis2dtw12<I>,
+    delay: D,
+    remaining: usize,
+    fifo: Option<bool>,
+    pending: Option<AccelerationData>,
+}
+
+impl<I: Interface> Lis2dtw12<I> {
+    /// Iterate over the next `count` new samples in **mg**
+    ///
+    /// Waits for each sample using the data-ready status, or the FIFO level when the FIFO is enabled,
+    /// so each sample is yielded exactly once. The status is polled 8 times per sample period.
+    ///
+    /// # NOTE
+    ///
+    /// Errors are yielded as items of the iterator:
+    ///
+    /// - [`Error::Overrun`] if samples were lost because they were read slower than the ODR (OVR in
+    ///   STATUS_DUP, or FIFO_OVR when the FIFO is enabled). It is yielded before the next sample and does
+    ///   not count towards `count`.
+    /// - [`Error::InvalidConfig`] if the device is powered down
+    /// - [`Error::Timeout`] if no new sample was available within 2 sample periods
+    ///
+    /// Any error other than [`Error::Overrun`] ends the iteration.
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `delay`: Delay used between the polls of the data-ready status
+    /// - `count`: Number of samples to yield
+    pub fn samples<D: DelayNs>(&mut self, delay: D, count: usize) -> Samples<'_, I, D> {
+        Samples {
+            device: self,
+            delay,
+            remaining: count,
+            fifo: None,
+            pending: None,
+        }
+    }
+}
+
+impl<I: Interface, D: DelayNs> Samples<'_, I, D> {
+    /// Wait for the next sample and read it
+    ///
+    /// Returns the sample and whether samples were lost before it
+    fn read_next(&mut self) -> Result<(AccelerationData, bool), Error<I::Error>> {
+        let odr_hz = self.device.odr.as_hz(self.device.mode);
+        if odr_hz == 0.0 {
+            return Err(Error::InvalidConfig);
+        }
+        let period_us = 1_000_000.0 / odr_hz;
+        let timeout_us = libm::ceilf(TIMEOUT_SAMPLE_PERIODS * period_us) as u32;
+        let interval_us = libm::ceilf(period_us / POLLS_PER_SAMPLE_PERIOD) as u32;
+        let fifo = match self.fifo {
+            Some(fifo) => fifo,
+            None => {
+                let fifo = self.device.get_fifo_mode()? != FifoMode::Bypass;
+                self.fifo = Some(fifo);
+                fifo
+            }
+        };
+
+        let overrun = if fifo {
+            let status = self.device.wait_for(
+                Register::FIFO_SAMPLES,
+                &mut self.delay,
+                interval_us,
+                timeout_us,
+                |v| FifoSamplesStatus::from(v).samples > 0,
+            )?;
+            FifoSamplesStatus::from(status).overrun
+        } else {
+            let status = self.device.wait_for(
+                Register::STATUS_DUP,
+                &mut self.delay,
+                interval_us,
+                timeout_us,
+                |v| v & DRDY != 0,
+            )?;
+            status & OVR != 0
+        };
+        let sample = self.device.get_accel_data().map_err(Error::Interface)?;
+        self.device
+            .record_samples(1, overrun && !fifo, overrun && fifo);
+        Ok((sample, overrun))
+    }
+}
+
+impl<I: Interface, D: DelayNs> Iterator for Samples<'_, I, D> {
+    type Item = Result<AccelerationData, Error<I::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(sample) = self.pending.take() {
+            return Some(Ok(sample));
+        }
+        if self.remaining == 0 {
+            return None;
+        }
+        match self.read_next() {
+            Ok((sample, overrun)) => {
+                self.remaining -= 1;
+                if overrun {
+                    self.pending = Some(sample);
+                    Some(Err(Error::Overrun))
+                } else {
+                    Some(Ok(sample))
+                }
+            }
+            Err(e) => {
+                self.remaining = 0;
+                Some(Err(e))
+            }
+        }
+    }
+}
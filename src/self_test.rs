@@ -0,0 +1,51 @@
+use crate::AccelerationData;
+
+/// Minimum self-test output change in **mg** @ FS = ±4 g
+pub const SELF_TEST_MIN_MG: f32 = 70.0;
+/// Maximum self-test output change in **mg** @ FS = ±4 g
+pub const SELF_TEST_MAX_MG: f32 = 1500.0;
+
+/// Self-test mode
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SelfTest {
+    /// Normal mode (self-test disabled)
+    #[default]
+    Disabled = 0b00,
+    /// Positive sign self-test
+    Positive = 0b01,
+    /// Negative sign self-test
+    Negative = 0b10,
+}
+
+/// Result of the self-test procedure
+///
+/// Returned by [`Lis2dtw12::run_self_test`](crate::Lis2dtw12::run_self_test)
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SelfTestResult {
+    /// Average output with self-test disabled in **mg**
+    pub normal: AccelerationData,
+    /// Average output with positive self-test in **mg**
+    pub self_test: AccelerationData,
+}
+
+impl SelfTestResult {
+    /// Get the output change caused by the self-test in **mg**
+    pub fn difference(&self) -> AccelerationData {
+        AccelerationData {
+            x: self.self_test.x - self.normal.x,
+            y: self.self_test.y - self.normal.y,
+            z: self.self_test.z - self.normal.z,
+        }
+    }
+
+    /// Check if the output change of all axes is within the limits of the datasheet
+    /// ([`SELF_TEST_MIN_MG`] to [`SELF_TEST_MAX_MG`])
+    pub fn passed(&self) -> bool {
+        let d = self.difference();
+        [d.x, d.y, d.z]
+            .iter()
+            .all(|v| (SELF_TEST_MIN_MG..=SELF_TEST_MAX_MG).contains(&libm::fabsf(*v)))
+    }
+}
@@ -0,0 +1,414 @@
+use core::f32::consts::PI;
+
+use crate::{RawAccelerationData, SampleFormat};
+
+/// Window function applied before the FFT
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Window {
+    /// Rectangular window (no windowing)
+    Rectangular,
+    /// Hann window
+    #[default]
+    Hann,
+    /// Hamming window
+    Hamming,
+}
+
+impl Window {
+    fn coefficient(self, n: usize, len: usize) -> f32 {
+        let phase = 2.0 * PI * n as f32 / len as f32;
+        match self {
+            Window::Rectangular => 1.0,
+            Window::Hann => 0.5 - 0.5 * libm::cosf(phase),
+            Window::Hamming => 0.54 - 0.46 * libm::cosf(phase),
+        }
+    }
+}
+
+/// Signal the spectrum is computed of
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Source {
+    /// X-axis acceleration
+    X,
+    /// Y-axis acceleration
+    Y,
+    /// Z-axis acceleration
+    Z,
+    /// Vector magnitude of the acceleration
+    Magnitude,
+}
+
+/// Spectral peak
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Peak {
+    /// Index of the bin
+    pub bin: usize,
+    /// Frequency in Hz (interpolated between the neighbouring bins)
+    pub frequency_hz: f32,
+    /// Amplitude in **mg**
+    pub amplitude: f32,
+}
+
+/// Amplitude spectrum
+///
+/// Contains `N / 2 + 1` bins for a window of `N` samples, from DC to the Nyquist frequency
+#[derive(Debug)]
+pub struct Spectrum<'a> {
+    bins: &'a [f32],
+    bin_width_hz: f32,
+    bandwidth_hz: f32,
+}
+
+impl<'a> Spectrum<'a> {
+    /// Get the amplitudes of the bins in **mg**
+    pub fn bins(&self) -> &'a [f32] {
+        self.bins
+    }
+
+    /// Get the frequency resolution in Hz
+    pub fn bin_width_hz(&self) -> f32 {
+        self.bin_width_hz
+    }
+
+    /// Get the frequency of a bin in Hz
+    pub fn frequency_hz(&self, bin: usize) -> f32 {
+        bin as f32 * self.bin_width_hz
+    }
+
+    /// Get the bandwidth of the digital filter of the device in Hz
+    ///
+    /// Bins above this frequency are attenuated and ignored by the peak search
+    pub fn bandwidth_hz(&self) -> f32 {
+        self.bandwidth_hz
+    }
+
+    /// Iterate over the bins as `(frequency in Hz, amplitude in mg)`
+    pub fn iter(&self) -> impl Iterator<Item = (f32, f32)> + '_ {
+        self.bins
+            .iter()
+            .enumerate()
+            .map(|(bin, amplitude)| (self.frequency_hz(bin), *amplitude))
+    }
+
+    /// Get the dominant frequency (largest peak, excluding DC) within the bandwidth
+    pub fn dominant(&self) -> Option<Peak> {
+        self.peak_in(1, self.last_bin())
+    }
+
+    /// Get the n-th harmonic of the dominant frequency
+    ///
+    /// The peak is searched within one bin around `n` times the dominant frequency.
+    /// `harmonic(1)` is the dominant frequency itself.
+    ///
+    /// # Returns
+    ///
+    /// - The harmonic peak, or `None` if it lies outside of the bandwidth
+    pub fn harmonic(&self, n: usize) -> Option<Peak> {
+        let fundamental = self.dominant()?;
+        let center = libm::roundf(fundamental.frequency_hz * n as f32 / self.bin_width_hz) as usize;
+        if n == 0 || center > self.last_bin() {
+            return None;
+        }
+        self.peak_in(
+            center.saturating_sub(1).max(1),
+            (center + 1).min(self.last_bin()),
+        )
+    }
+
+    fn last_bin(&self) -> usize {
+        let bandwidth_bin = (self.bandwidth_hz / self.bin_width_hz) as usize;
+        bandwidth_bin.min(self.bins.len() - 1)
+    }
+
+    fn peak_in(&self, first: usize, last: usize) -> Option<Peak> {
+        if first > last {
+            return None;
+        }
+        let (bin, amplitude) =
+            self.bins[first..=last]
+                .iter()
+                .enumerate()
+                .fold((first, 0.0f32), |max, (i, a)| {
+                    if *a > max.1 {
+                        (first + i, *a)
+                    } else {
+                        max
+                    }
+                });
+
+        // Parabolic interpolation of the peak position
+        let offset = if bin > 0 && bin + 1 < self.bins.len() {
+            let (l, c, r) = (self.bins[bin - 1], self.bins[bin], self.bins[bin + 1]);
+            let denominator = l - 2.0 * c + r;
+            if denominator != 0.0 {
+                (0.5 * (l - r) / denominator).clamp(-0.5, 0.5)
+            } else {
+                0.0
+            }
+        } else {
+            0.0
+        };
+
+        Some(Peak {
+            bin,
+            frequency_hz: (bin as f32 + offset) * self.bin_width_hz,
+            amplitude,
+        })
+    }
+}
+
+/// Load a window of RAW acceleration data into the FFT buffer
+///
+/// The samples are converted to **mg** and the mean (gravity) is removed.
+///
+/// # Returns
+///
+/// - Number of samples written to `buffer`
+pub fn load(
+    samples: &[RawAccelerationData],
+    format: &SampleFormat,
+    source: Source,
+    buffer: &mut [f32],
+) -> usize {
+    let count = samples.len().min(buffer.len());
+    for (value, sample) in buffer.iter_mut().zip(samples) {
+        let mg = format.to_mg(sample);
+        *value = match source {
+            Source::X => mg.x,
+            Source::Y => mg.y,
+            Source::Z => mg.z,
+            Source::Magnitude => mg.magnitude(),
+        };
+    }
+    if count > 0 {
+        let mean = buffer[..count].iter().sum::<f32>() / count as f32;
+        buffer[..count].iter_mut().for_each(|v| *v -= mean);
+    }
+    count
+}
+
+/// Compute the amplitude spectrum of a window of samples
+///
+/// The FFT is computed in place, no additional memory is needed.
+/// After the call the first `N / 2 + 1` elements of the buffer contain the amplitude spectrum.
+///
+/// # ARGUMENTS
+///
+/// - `buffer`: Window of `N` samples in **mg**, `N` must be a power of two (at least 4)
+/// - `window`: Window function applied before the FFT
+/// - `format`: Format the samples were taken with, used for the sample rate and bandwidth
+///   (see [`Lis2dtw12::sample_format`](crate::Lis2dtw12::sample_format))
+///
+/// # Returns
+///
+/// - The amplitude spectrum, or `None` if `N` is not a power of two or the device is powered down
+pub fn compute<'a>(
+    buffer: &'a mut [f32],
+    window: Window,
+    format: &SampleFormat,
+) -> Option<Spectrum<'a>> {
+    let len = buffer.len();
+    let sample_rate = format.sample_rate_hz();
+    if len < 4 || !len.is_power_of_two() || sample_rate == 0.0 {
+        return None;
+    }
+
+    let mut gain = 0.0;
+    for (n, value) in buffer.iter_mut().enumerate() {
+        let w = window.coefficient(n, len);
+        gain += w;
+        *value *= w;
+    }
+
+    real_fft(buffer);
+
+    // Single-sided amplitude spectrum, corrected for the window gain
+    let half = len / 2;
+    let nyquist = libm::fabsf(buffer[1]) / gain;
+    for bin in 0..half {
+        let (re, im) = (buffer[2 * bin], buffer[2 * bin + 1]);
+        buffer[bin] = if bin == 0 {
+            libm::fabsf(re) / gain
+        } else {
+            2.0 * libm::sqrtf(re * re + im * im) / gain
+        };
+    }
+    buffer[half] = nyquist;
+
+    Some(Spectrum {
+        bins: &buffer[..=half],
+        bin_width_hz: sample_rate / len as f32,
+        bandwidth_hz: format.bandwidth_hz(),
+    })
+}
+
+/// In place FFT of a real signal
+///
+/// The signal is packed into `N / 2` complex values, transformed with a complex FFT and then split.
+/// The result contains the bins `0..N / 2` as interleaved complex values, with the real valued
+/// Nyquist bin stored in the imaginary part of the (real valued) DC bin.
+fn real_fft(buffer: &mut [f32]) {
+    let half = buffer.len() / 2;
+    complex_fft(buffer);
+
+    let (re0, im0) = (buffer[0], buffer[1]);
+    buffer[0] = re0 + im0;
+    buffer[1] = re0 - im0;
+
+    for k in 1..=half / 2 {
+        let j = half - k;
+        let (zk_re, zk_im) = (buffer[2 * k], buffer[2 * k + 1]);
+        let (zj_re, zj_im) = (buffer[2 * j], buffer[2 * j + 1]);
+
+        // Spectrum of the even and odd samples
+        let (even_re, even_im) = (0.5 * (zk_re + zj_re), 0.5 * (zk_im - zj_im));
+        let (odd_re, odd_im) = (0.5 * (zk_im + zj_im), -0.5 * (zk_re - zj_re));
+
+        let phase = -PI * k as f32 / half as f32;
+        let (w_re, w_im) = (libm::cosf(phase), libm::sinf(phase));
+        let (t_re, t_im) = (w_re * odd_re - w_im * odd_im, w_re * odd_im + w_im * odd_re);
+
+        buffer[2 * k] = even_re + t_re;
+        buffer[2 * k + 1] = even_im + t_im;
+        if j != k {
+            buffer[2 * j] = even_re - t_re;
+            buffer[2 * j + 1] = -(even_im - t_im);
+        }
+    }
+}
+
+/// In place radix-2 complex FFT on interleaved (re, im) values
+fn complex_fft(buffer: &mut [f32]) {
+    let n = buffer.len() / 2;
+
+    // Bit reversal permutation
+    let mut j = 0;
+    for i in 1..n {
+        let mut bit = n >> 1;
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+        j |= bit;
+        if i < j {
+            buffer.swap(2 * i, 2 * j);
+            buffer.swap(2 * i + 1, 2 * j + 1);
+        }
+    }
+
+    let mut len = 2;
+    while len <= n {
+        let phase = -2.0 * PI / len as f32;
+        let (step_re, step_im) = (libm::cosf(phase), libm::sinf(phase));
+        for start in (0..n).step_by(len) {
+            let (mut w_re, mut w_im) = (1.0f32, 0.0f32);
+            for k in 0..len / 2 {
+                let a = 2 * (start + k);
+                let b = 2 * (start + k + len / 2);
+                let (t_re, t_im) = (
+                    w_re * buffer[b] - w_im * buffer[b + 1],
+                    w_re * buffer[b + 1] + w_im * buffer[b],
+                );
+                buffer[b] = buffer[a] - t_re;
+                buffer[b + 1] = buffer[a + 1] - t_im;
+                buffer[a] += t_re;
+                buffer[a + 1] += t_im;
+                (w_re, w_im) = (
+                    w_re * step_re - w_im * step_im,
+                    w_re * step_im + w_im * step_re,
+                );
+            }
+        }
+        len <<= 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Mode, OutputDataRate};
+
+    const LEN: usize = 64;
+
+    /// 400 Hz sample rate, 200 Hz bandwidth: bins are 6.25 Hz wide
+    fn format() -> SampleFormat {
+        SampleFormat {
+            mode: Mode::HighPerformance,
+            odr: OutputDataRate::Hz400,
+            ..Default::default()
+        }
+    }
+
+    fn tone(bin: usize, amplitude: f32) -> [f32; LEN] {
+        core::array::from_fn(|n| amplitude * libm::sinf(2.0 * PI * (bin * n) as f32 / LEN as f32))
+    }
+
+    fn assert_close(actual: f32, expected: f32) {
+        ::core::assert!(
+            libm::fabsf(actual - expected) < 0.01,
+            "{actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn tone_in_expected_bin() {
+        for window in [Window::Rectangular, Window::Hann, Window::Hamming] {
+            let mut buffer = tone(8, 100.0);
+            let spectrum = compute(&mut buffer, window, &format()).unwrap();
+            ::core::assert_eq!(spectrum.bins().len(), LEN / 2 + 1);
+            assert_close(spectrum.bin_width_hz(), 6.25);
+
+            let peak = spectrum.dominant().unwrap();
+            ::core::assert_eq!(peak.bin, 8);
+            assert_close(peak.frequency_hz, 50.0);
+            assert_close(spectrum.frequency_hz(peak.bin), 50.0);
+            assert_close(peak.amplitude, 100.0);
+        }
+    }
+
+    #[test]
+    fn rectangular_tone_has_no_leakage() {
+        let mut buffer = tone(5, 100.0);
+        let spectrum = compute(&mut buffer, Window::Rectangular, &format()).unwrap();
+        for (bin, amplitude) in spectrum.bins().iter().enumerate() {
+            let expected = if bin == 5 { 100.0 } else { 0.0 };
+            assert_close(*amplitude, expected);
+        }
+    }
+
+    #[test]
+    fn dc_in_bin_zero() {
+        let mut buffer = [250.0; LEN];
+        let spectrum = compute(&mut buffer, Window::Rectangular, &format()).unwrap();
+        assert_close(spectrum.bins()[0], 250.0);
+        ::core::assert!(spectrum.bins()[1..].iter().all(|a| *a < 0.01));
+    }
+
+    #[test]
+    fn load_removes_dc() {
+        let samples = [RawAccelerationData {
+            x: 0,
+            y: 0,
+            z: 16384,
+        }; LEN];
+        let mut buffer = [0.0; LEN];
+        ::core::assert_eq!(load(&samples, &format(), Source::Z, &mut buffer), LEN);
+        let spectrum = compute(&mut buffer, Window::Hann, &format()).unwrap();
+        ::core::assert!(spectrum.bins().iter().all(|a| *a < 0.01));
+    }
+
+    #[test]
+    fn invalid_length() {
+        let mut buffer = [0.0; 48];
+        ::core::assert!(compute(&mut buffer, Window::Hann, &format()).is_none());
+        let mut buffer = [0.0; LEN];
+        let powered_down = SampleFormat {
+            odr: OutputDataRate::PowerDown,
+            ..format()
+        };
+        ::core::assert!(compute(&mut buffer, Window::Hann, &powered_down).is_none());
+    }
+}
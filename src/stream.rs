@@ -0,0 +1,233 @@
+use core::future::Future;
+use core::pin::Pin;
+use core::task::{Context, Poll};
+
+use embedded_hal_async::digital::Wait;
+use futures_core::Stream;
+
+use crate::registers::*;
+use crate::{AccelerationData, Error, FifoMode, Interface, Lis2dtw12Async};
+
+/// Maximum number of samples stored in the FIFO
+const FIFO_DEPTH: usize = 32;
+
+/// Sample yielded by a [`SampleStream`]
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct StreamSample {
+    /// Acceleration data in **mg**
+    pub data: AccelerationData,
+    /// Samples were lost before this sample (OVR in STATUS_DUP, or FIFO_OVR when the FIFO is enabled)
+    pub overrun: bool,
+}
+
+/// Source of the samples of a [`SampleStream`]
+///
+/// Holds the driver, the interrupt pin and the batch of samples drained from the FIFO
+pub struct SampleSource<'a, I, P> {
+    device: &'a mut Lis2dtw12Async<I>,
+    pin: P,
+    /// Interrupt configuration, read on the first sample
+    config: Option<PinConfig>,
+    batch: [AccelerationData; FIFO_DEPTH],
+    len: usize,
+    pos: usize,
+}
+
+/// Interrupt configuration of a [`SampleSource`]
+#[derive(Copy, Clone)]
+struct PinConfig {
+    /// FIFO enabled, the pin carries the FIFO threshold interrupt
+    fifo: bool,
+    /// Interrupt active low (H_LACTIVE)
+    active_low: bool,
+    /// Pulsed data-ready interrupt (DRDY_PULSED)
+    pulsed: bool,
+}
+
+pin_project_lite::pin_project! {
+    /// Stream of acceleration samples
+    ///
+    /// Created by [`Lis2dtw12Async::sample_stream`], yields each new sample once. Errors don't end the stream.
+    pub struct SampleStream<S, Fut> {
+        source: Option<S>,
+        step: fn(S) -> Fut,
+        #[pin]
+        future: Option<Fut>,
+    }
+}
+
+impl<I: Interface> Lis2dtw12Async<I> {
+    /// Create a stream of acceleration samples backed by an interrupt pin
+    ///
+    /// The pin has to carry the data-ready interrupt, or the FIFO threshold interrupt when the FIFO is enabled
+    /// (see [`Self::route`]). With the FIFO enabled, the FIFO is drained in batches on each interrupt and
+    /// the samples are yielded one by one.
+    ///
+    /// # NOTE
+    ///
+    /// The FIFO mode, the interrupt polarity (H_LACTIVE) and the data-ready mode (DRDY_PULSED) are read
+    /// on the first sample. The stream waits for the pin to be at its active level, or for the active edge
+    /// of the pulse with a pulsed data-ready interrupt.
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `pin`: Interrupt pin, implementing `embedded_hal_async::digital::Wait`
+    ///
+    /// # Errors
+    ///
+    /// Yields [`Error::Pin`] if waiting for the pin failed
+    #[allow(clippy::type_complexity)]
+    pub fn sample_stream<P: Wait>(
+        &mut self,
+        pin: P,
+    ) -> SampleStream<
+        SampleSource<'_, I, P>,
+        impl Future<
+            Output = (
+                Result<StreamSample, Error<I::Error>>,
+                SampleSource<'_, I, P>,
+            ),
+        >,
+    > {
+        SampleStream {
+            source: Some(SampleSource {
+                device: self,
+                pin,
+                config: None,
+                batch: [AccelerationData::default(); FIFO_DEPTH],
+                len: 0,
+                pos: 0,
+            }),
+            step: step::<I, P>,
+            future: None,
+        }
+    }
+}
+
+async fn step<I: Interface, P: Wait>(
+    mut source: SampleSource<'_, I, P>,
+) -> (
+    Result<StreamSample, Error<I::Error>>,
+    SampleSource<'_, I, P>,
+) {
+    let sample = source.next_sample().await;
+    (sample, source)
+}
+
+impl<I: Interface, P: Wait> SampleSource<'_, I, P> {
+    async fn next_sample(&mut self) -> Result<StreamSample, Error<I::Error>> {
+        if self.pos < self.len {
+            self.pos += 1;
+            return Ok(StreamSample {
+                data: self.batch[self.pos - 1],
+                overrun: false,
+            });
+        }
+
+        let config = match self.config {
+            Some(config) => config,
+            None => {
+                let fifo = self.device.get_fifo_mode().await? != FifoMode::Bypass;
+                let ctrl3 = self
+                    .device
+                    .read_reg(Register::CTRL3)
+                    .await
+                    .map_err(Error::Interface)?;
+                let ctrl7 = self
+                    .device
+                    .read_reg(Register::CTRL7)
+                    .await
+                    .map_err(Error::Interface)?;
+                let config = PinConfig {
+                    fifo,
+                    active_low: ctrl3 & H_LACTIVE != 0,
+                    // The FIFO threshold interrupt is never pulsed
+                    pulsed: !fifo && ctrl7 & DRDY_PULSED != 0,
+                };
+                self.config = Some(config);
+                config
+            }
+        };
+
+        loop {
+            // A pulse may still be active after the previous sample was read, wait for the next one
+            let wait = match (config.pulsed, config.active_low) {
+                (true, true) => self.pin.wait_for_falling_edge().await,
+                (true, false) => self.pin.wait_for_rising_edge().await,
+                (false, true) => self.pin.wait_for_low().await,
+                (false, false) => self.pin.wait_for_high().await,
+            };
+            wait.map_err(|_| Error::Pin)?;
+
+            if config.fifo {
+                let status = self
+                    .device
+                    .get_fifo_samples_status()
+                    .await
+                    .map_err(Error::Interface)?;
+                let len = (status.samples as usize).min(FIFO_DEPTH);
+                if len == 0 {
+                    continue;
+                }
+                for i in 0..len {
+                    self.batch[i] = self
+                        .device
+                        .get_accel_data()
+                        .await
+                        .map_err(Error::Interface)?;
+                }
+                self.device
+                    .record_samples(len as u32, false, status.overrun);
+                self.len = len;
+                self.pos = 1;
+                return Ok(StreamSample {
+                    data: self.batch[0],
+                    overrun: status.overrun,
+                });
+            }
+
+            let status = self
+                .device
+                .read_reg(Register::STATUS_DUP)
+                .await
+                .map_err(Error::Interface)?;
+            if status & DRDY == 0 {
+                continue;
+            }
+            let data = self
+                .device
+                .get_accel_data()
+                .await
+                .map_err(Error::Interface)?;
+            let overrun = status & OVR != 0;
+            self.device.record_samples(1, overrun, false);
+            return Ok(StreamSample { data, overrun });
+        }
+    }
+}
+
+impl<S, T, Fut: Future<Output = (T, S)>> Stream for SampleStream<S, Fut> {
+    type Item = T;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let mut this = self.project();
+        if this.future.is_none() {
+            let Some(source) = this.source.take() else {
+                return Poll::Ready(None);
+            };
+            this.future.set(Some((this.step)(source)));
+        }
+        let Some(future) = this.future.as_mut().as_pin_mut() else {
+            return Poll::Ready(None);
+        };
+        match future.poll(cx) {
+            Poll::Ready((item, source)) => {
+                this.future.set(None);
+                *this.source = Some(source);
+                Poll::Ready(Some(item))
+            }
+            Poll::Pending => Poll::Pending,
+        }
+    }
+}
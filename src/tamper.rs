@@ -0,0 +1,98 @@
+use crate::{ReferenceDeviation, ReferenceMode};
+
+/// Tamper detector configuration
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TamperConfig {
+    /// Deviation from the installed pose in **mg** that triggers the wake-up detection
+    ///
+    /// Default value is 125 mg
+    pub motion_threshold_mg: f32,
+    /// Tilt away from the installed pose in degrees above which the asset is reported as tilted
+    ///
+    /// Default value is 10°
+    pub tilt_threshold_deg: f32,
+}
+
+impl Default for TamperConfig {
+    fn default() -> Self {
+        Self {
+            motion_threshold_mg: 125.0,
+            tilt_threshold_deg: 10.0,
+        }
+    }
+}
+
+/// Tamper event
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TamperEvent {
+    /// The asset was moved (wake-up detected), but is still in its installed pose
+    Moved(ReferenceDeviation),
+    /// The asset is tilted away from its installed pose
+    Tilted(ReferenceDeviation),
+    /// The asset returned to its installed pose after being tilted
+    Restored(ReferenceDeviation),
+}
+
+/// Tamper detector
+///
+/// Captures the installed pose of a mounted asset using the high-pass filter reference mode
+/// and reports when it is moved or tilted away from it.
+///
+/// Arm it with [`Lis2dtw12::arm_tamper_detector`](crate::Lis2dtw12::arm_tamper_detector) and poll it with
+/// [`Lis2dtw12::poll_tamper_detector`](crate::Lis2dtw12::poll_tamper_detector).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct TamperDetector {
+    pub(crate) config: TamperConfig,
+    pub(crate) reference: Option<ReferenceMode>,
+    pub(crate) tilted: bool,
+}
+
+impl TamperDetector {
+    /// Create a new tamper detector
+    pub fn new(config: TamperConfig) -> Self {
+        Self {
+            config,
+            reference: None,
+            tilted: false,
+        }
+    }
+
+    /// Get the configuration of the tamper detector
+    pub fn config(&self) -> &TamperConfig {
+        &self.config
+    }
+
+    /// Get the reference mode of the installed pose, if armed
+    pub fn reference(&self) -> Option<&ReferenceMode> {
+        self.reference.as_ref()
+    }
+
+    /// Check if the detector is armed
+    pub fn is_armed(&self) -> bool {
+        self.reference.is_some()
+    }
+
+    /// Check if the asset is currently tilted away from its installed pose
+    pub fn is_tilted(&self) -> bool {
+        self.tilted
+    }
+
+    pub(crate) fn update(
+        &mut self,
+        deviation: ReferenceDeviation,
+        moved: bool,
+    ) -> Option<TamperEvent> {
+        let tilted = deviation.tilt_deg > self.config.tilt_threshold_deg;
+        let event = match (self.tilted, tilted) {
+            (false, true) => Some(TamperEvent::Tilted(deviation)),
+            (true, false) => Some(TamperEvent::Restored(deviation)),
+            (false, false) if moved => Some(TamperEvent::Moved(deviation)),
+            _ => None,
+        };
+        self.tilted = tilted;
+        event
+    }
+}
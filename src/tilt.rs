@@ -0,0 +1,36 @@
+use crate::AccelerationData;
+
+/// Reference gravity vector for the tilt wake-up
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TiltReference {
+    /// Use the current acceleration as reference
+    #[default]
+    Current,
+    /// Use the given acceleration in **mg** as reference
+    Acceleration(AccelerationData),
+}
+
+impl TiltReference {
+    /// Get the deviation in **mg** caused by tilting a gravity vector by the given angle
+    ///
+    /// # ARGUMENTS
+    ///
+    /// - `gravity_mg`: Magnitude of the gravity vector in **mg**
+    /// - `tilt_deg`: Tilt angle in degrees
+    ///
+    /// # Returns
+    ///
+    /// - Magnitude of the difference between the tilted and the original vector in **mg**
+    pub fn tilt_to_mg(gravity_mg: f32, tilt_deg: f32) -> f32 {
+        2.0 * gravity_mg * libm::sinf(tilt_deg.to_radians() / 2.0)
+    }
+
+    /// Get the tilt angle in degrees that causes the given deviation of a gravity vector
+    ///
+    /// Inverse of [`Self::tilt_to_mg`]
+    pub fn mg_to_tilt(gravity_mg: f32, deviation_mg: f32) -> f32 {
+        let ratio = (deviation_mg / (2.0 * gravity_mg)).clamp(-1.0, 1.0);
+        2.0 * libm::asinf(ratio).to_degrees()
+    }
+}
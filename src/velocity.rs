@@ -0,0 +1,459 @@
+use core::f32::consts::PI;
+
+use crate::{RawAccelerationData, SampleFormat};
+
+/// 1 mg in mm/s²
+const MG_TO_MM_S2: f32 = 9.806_65;
+
+/// Number of filter time constants to wait before the filters are considered settled
+const SETTLING_TIME_CONSTANTS: f32 = 5.0;
+
+/// Cutoff scaling of two cascaded first-order high-pass filters (sqrt(sqrt(2) - 1)),
+/// so that the -3 dB point of the cascade lies at the lower band limit
+const CASCADE_CUTOFF_SCALE: f32 = 0.643_594_3;
+
+/// Values of the three axes
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct AxisValues {
+    /// X-axis value
+    pub x: f32,
+    /// Y-axis value
+    pub y: f32,
+    /// Z-axis value
+    pub z: f32,
+}
+
+impl AxisValues {
+    /// Get the largest value of the three axes
+    pub fn max(&self) -> f32 {
+        self.x.max(self.y).max(self.z)
+    }
+}
+
+/// Machine class according to ISO 10816-1
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum MachineClass {
+    /// Class I: small machines (up to 15 kW)
+    #[default]
+    Small,
+    /// Class II: medium sized machines (15 kW to 75 kW, or up to 300 kW on special foundations)
+    Medium,
+    /// Class III: large machines on rigid foundations
+    LargeRigid,
+    /// Class IV: large machines on soft foundations
+    LargeSoft,
+}
+
+impl MachineClass {
+    /// Zone boundaries A/B, B/C and C/D in mm/s (RMS)
+    fn zone_limits(self) -> [f32; 3] {
+        match self {
+            MachineClass::Small => [0.71, 1.8, 4.5],
+            MachineClass::Medium => [1.12, 2.8, 7.1],
+            MachineClass::LargeRigid => [1.8, 4.5, 11.2],
+            MachineClass::LargeSoft => [2.8, 7.1, 18.0],
+        }
+    }
+}
+
+/// Vibration severity zone according to ISO 10816
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum SeverityZone {
+    /// Zone A: vibration of newly commissioned machines
+    A,
+    /// Zone B: acceptable for unrestricted long-term operation
+    B,
+    /// Zone C: unsatisfactory for long-term operation
+    C,
+    /// Zone D: severe enough to cause damage
+    D,
+}
+
+impl SeverityZone {
+    /// Classify a velocity RMS value in mm/s for the given machine class
+    pub fn classify(velocity_rms: f32, class: MachineClass) -> Self {
+        let [ab, bc, cd] = class.zone_limits();
+        if velocity_rms <= ab {
+            SeverityZone::A
+        } else if velocity_rms <= bc {
+            SeverityZone::B
+        } else if velocity_rms <= cd {
+            SeverityZone::C
+        } else {
+            SeverityZone::D
+        }
+    }
+}
+
+/// Velocity meter configuration
+#[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VelocityConfig {
+    /// Format the samples are taken with (see [`Lis2dtw12::sample_format`](crate::Lis2dtw12::sample_format))
+    pub format: SampleFormat,
+    /// Lower limit of the frequency band in Hz
+    ///
+    /// Also controls the drift of the integration. Default value is 10 Hz (ISO 10816)
+    pub low_cutoff_hz: f32,
+    /// Upper limit of the frequency band in Hz
+    ///
+    /// No additional filtering is done above the bandwidth of the device. Default value is 1000 Hz (ISO 10816)
+    pub high_cutoff_hz: f32,
+    /// Also integrate the velocity to displacement
+    pub displacement: bool,
+}
+
+impl Default for VelocityConfig {
+    fn default() -> Self {
+        Self {
+            format: SampleFormat::default(),
+            low_cutoff_hz: 10.0,
+            high_cutoff_hz: 1000.0,
+            displacement: false,
+        }
+    }
+}
+
+/// First-order high-pass filter (unity gain at high frequencies)
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct HighPass {
+    input: f32,
+    output: f32,
+}
+
+impl HighPass {
+    fn update(&mut self, alpha: f32, input: f32) -> f32 {
+        self.output = alpha * self.output + 0.5 * (1.0 + alpha) * (input - self.input);
+        self.input = input;
+        self.output
+    }
+}
+
+/// Trapezoidal integrator followed by a high-pass filter to remove the drift
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Integrator {
+    input: f32,
+    sum: f32,
+    drift: HighPass,
+}
+
+impl Integrator {
+    fn update(&mut self, alpha: f32, dt: f32, input: f32) -> f32 {
+        self.sum += 0.5 * (input + self.input) * dt;
+        self.input = input;
+        self.drift.update(alpha, self.sum)
+    }
+}
+
+/// Signal chain of one axis
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+struct Axis {
+    high_pass: HighPass,
+    low_pass: f32,
+    velocity: Integrator,
+    displacement: Integrator,
+    velocity_square_sum: f32,
+    min_displacement: f32,
+    max_displacement: f32,
+}
+
+/// Vibration velocity meter
+///
+/// Machine vibration standards (ISO 10816) grade the severity by the velocity RMS in mm/s.
+/// The acceleration of each axis is band-pass filtered (first-order high-pass and low-pass),
+/// integrated to velocity and optionally to displacement. Each integration is followed by a
+/// high-pass filter at the lower band limit to control the drift.
+///
+/// The statistics are accumulated until [`VelocityMeter::reset_statistics`] is called,
+/// samples taken while the filters are settling are ignored.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VelocityMeter {
+    config: VelocityConfig,
+    dt: f32,
+    hp_alpha: f32,
+    lp_alpha: f32,
+    settling_samples: u32,
+    primed: bool,
+    samples: u32,
+    statistics_samples: u32,
+    axes: [Axis; 3],
+}
+
+impl VelocityMeter {
+    /// Create a new velocity meter
+    pub fn new(config: VelocityConfig) -> Self {
+        let sample_rate = config.format.sample_rate_hz();
+        let dt = if sample_rate > 0.0 {
+            1.0 / sample_rate
+        } else {
+            0.0
+        };
+        let hp_cutoff = config.low_cutoff_hz * CASCADE_CUTOFF_SCALE;
+        // The digital filter of the device already limits the band at its bandwidth
+        let lp_alpha = if config.high_cutoff_hz < config.format.bandwidth_hz() {
+            let lp_rc = 1.0 / (2.0 * PI * config.high_cutoff_hz.max(config.low_cutoff_hz));
+            dt / (lp_rc + dt)
+        } else {
+            1.0
+        };
+
+        let mut meter = Self {
+            config,
+            dt,
+            hp_alpha: libm::expf(-2.0 * PI * hp_cutoff * dt),
+            lp_alpha,
+            settling_samples: (SETTLING_TIME_CONSTANTS * sample_rate / (2.0 * PI * hp_cutoff))
+                as u32,
+            primed: false,
+            samples: 0,
+            statistics_samples: 0,
+            axes: [Axis::default(); 3],
+        };
+        meter.reset_statistics();
+        meter
+    }
+
+    /// Get the configuration of the velocity meter
+    pub fn config(&self) -> &VelocityConfig {
+        &self.config
+    }
+
+    /// Process a batch of RAW samples (e.g. a FIFO drain)
+    pub fn process(&mut self, samples: &[RawAccelerationData]) {
+        samples.iter().for_each(|s| self.process_sample(s));
+    }
+
+    /// Process a single RAW sample
+    pub fn process_sample(&mut self, sample: &RawAccelerationData) {
+        let mg = self.config.format.to_mg(sample);
+        let values = [mg.x, mg.y, mg.z];
+
+        if !self.primed {
+            // Start the high-pass filters at the first sample to avoid a gravity step response
+            for (axis, value) in self.axes.iter_mut().zip(values) {
+                axis.high_pass.input = value * MG_TO_MM_S2;
+            }
+            self.primed = true;
+        }
+
+        let settled = self.is_settled();
+        for (axis, value) in self.axes.iter_mut().zip(values) {
+            let acceleration = axis.high_pass.update(self.hp_alpha, value * MG_TO_MM_S2);
+            axis.low_pass += self.lp_alpha * (acceleration - axis.low_pass);
+            let velocity = axis.velocity.update(self.hp_alpha, self.dt, axis.low_pass);
+
+            if settled {
+                axis.velocity_square_sum += velocity * velocity;
+            }
+            if self.config.displacement {
+                let displacement = axis.displacement.update(self.hp_alpha, self.dt, velocity);
+                if settled {
+                    axis.min_displacement = axis.min_displacement.min(displacement);
+                    axis.max_displacement = axis.max_displacement.max(displacement);
+                }
+            }
+        }
+
+        self.samples = self.samples.saturating_add(1);
+        if settled {
+            self.statistics_samples = self.statistics_samples.saturating_add(1);
+        }
+    }
+
+    /// Check if the filters have settled and the statistics are being accumulated
+    pub fn is_settled(&self) -> bool {
+        self.samples >= self.settling_samples
+    }
+
+    /// Reset the accumulated statistics, keeping the filter states
+    pub fn reset_statistics(&mut self) {
+        self.statistics_samples = 0;
+        for axis in self.axes.iter_mut() {
+            axis.velocity_square_sum = 0.0;
+            axis.min_displacement = f32::MAX;
+            axis.max_displacement = f32::MIN;
+        }
+    }
+
+    /// Get the velocity RMS of each axis in mm/s
+    ///
+    /// # Returns
+    ///
+    /// - The velocity RMS, or `None` if no statistics have been accumulated yet
+    pub fn velocity_rms(&self) -> Option<AxisValues> {
+        if self.statistics_samples == 0 {
+            return None;
+        }
+        let n = self.statistics_samples as f32;
+        let [x, y, z] = self
+            .axes
+            .map(|axis| libm::sqrtf(axis.velocity_square_sum / n));
+        Some(AxisValues { x, y, z })
+    }
+
+    /// Get the peak-to-peak displacement of each axis in µm
+    ///
+    /// # Returns
+    ///
+    /// - The displacement, or `None` if displacement is disabled or no statistics have been accumulated yet
+    pub fn displacement_peak_to_peak(&self) -> Option<AxisValues> {
+        if !self.config.displacement || self.statistics_samples == 0 {
+            return None;
+        }
+        let [x, y, z] = self
+            .axes
+            .map(|axis| (axis.max_displacement - axis.min_displacement) * 1000.0);
+        Some(AxisValues { x, y, z })
+    }
+
+    /// Classify the vibration severity, using the largest velocity RMS of the three axes
+    ///
+    /// # Returns
+    ///
+    /// - The severity zone, or `None` if no statistics have been accumulated yet
+    pub fn severity(&self, class: MachineClass) -> Option<SeverityZone> {
+        self.velocity_rms()
+            .map(|rms| SeverityZone::classify(rms.max(), class))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Mode, OutputDataRate};
+
+    const SAMPLE_RATE: f32 = 1600.0;
+
+    fn format() -> SampleFormat {
+        SampleFormat {
+            mode: Mode::HighPerformance,
+            odr: OutputDataRate::Hz1600,
+            ..Default::default()
+        }
+    }
+
+    /// Convert mg to RAW data of [`format`]
+    fn raw(mg: f32) -> i16 {
+        let scale = format()
+            .to_mg(&RawAccelerationData {
+                x: 1 << 8,
+                y: 0,
+                z: 0,
+            })
+            .x
+            / 256.0;
+        libm::roundf(mg / scale) as i16
+    }
+
+    /// Sine on the X-axis and gravity on the Z-axis
+    fn samples(
+        amplitude_mg: f32,
+        frequency_hz: f32,
+        count: usize,
+    ) -> impl Iterator<Item = RawAccelerationData> {
+        (0..count).map(move |n| RawAccelerationData {
+            x: raw(amplitude_mg * libm::sinf(2.0 * PI * frequency_hz * n as f32 / SAMPLE_RATE)),
+            y: 0,
+            z: raw(1000.0),
+        })
+    }
+
+    #[test]
+    fn constant_acceleration_integrates_linearly() {
+        let mut integrator = Integrator::default();
+        // No drift removal
+        let mut previous = integrator.update(1.0, 0.01, 100.0);
+        ::core::assert!(libm::fabsf(previous - 0.5) < 1e-4);
+        for _ in 0..100 {
+            let velocity = integrator.update(1.0, 0.01, 100.0);
+            ::core::assert!(libm::fabsf(velocity - previous - 1.0) < 1e-3);
+            previous = velocity;
+        }
+        ::core::assert!(libm::fabsf(previous - 100.5) < 0.05);
+    }
+
+    #[test]
+    fn zero_mean_signal_does_not_drift() {
+        let mut integrator = Integrator::default();
+        let dt = 1.0 / SAMPLE_RATE;
+        // 20 samples per period, the velocity returns to zero after each period
+        for period in 0..50 {
+            let mut velocity = 0.0;
+            for n in 1..=20 {
+                velocity = integrator.update(1.0, dt, libm::sinf(2.0 * PI * n as f32 / 20.0));
+            }
+            ::core::assert!(
+                libm::fabsf(velocity) < 1e-4,
+                "drift {velocity} after {period} periods"
+            );
+        }
+    }
+
+    #[test]
+    fn drift_filter_bounds_offset() {
+        let meter = VelocityMeter::new(VelocityConfig {
+            format: format(),
+            ..Default::default()
+        });
+        let mut integrator = Integrator::default();
+        // A constant offset grows linearly without the drift filter, it converges to a constant with it
+        let mut velocity = 0.0;
+        for _ in 0..(10.0 * SAMPLE_RATE) as usize {
+            velocity = integrator.update(meter.hp_alpha, meter.dt, 100.0);
+        }
+        let settled = velocity;
+        for _ in 0..SAMPLE_RATE as usize {
+            velocity = integrator.update(meter.hp_alpha, meter.dt, 100.0);
+        }
+        ::core::assert!(libm::fabsf(velocity - settled) < 1e-3 * settled.abs().max(1.0));
+        // Steady state of a high-pass filtered ramp: slope times the filter time constant
+        let expected = 100.0 / (2.0 * PI * 10.0 * CASCADE_CUTOFF_SCALE);
+        ::core::assert!(
+            libm::fabsf(velocity - expected) < 0.05 * expected,
+            "{velocity} != {expected}"
+        );
+    }
+
+    #[test]
+    fn sine_velocity_rms() {
+        let mut meter = VelocityMeter::new(VelocityConfig {
+            format: format(),
+            ..Default::default()
+        });
+        let (amplitude_mg, frequency_hz) = (500.0, 80.0);
+        for sample in samples(amplitude_mg, frequency_hz, 2 * SAMPLE_RATE as usize) {
+            meter.process_sample(&sample);
+        }
+        ::core::assert!(meter.is_settled());
+
+        let rms = meter.velocity_rms().unwrap();
+        let expected = amplitude_mg * MG_TO_MM_S2 / (2.0 * PI * frequency_hz) / libm::sqrtf(2.0);
+        ::core::assert!(
+            libm::fabsf(rms.x - expected) < 0.02 * expected,
+            "{} != {expected}",
+            rms.x
+        );
+        // Gravity is removed and does not drift
+        ::core::assert!(rms.y < 0.01 && rms.z < 0.01, "{rms:?}");
+    }
+
+    #[test]
+    fn no_statistics_while_settling() {
+        let mut meter = VelocityMeter::new(VelocityConfig {
+            format: format(),
+            displacement: true,
+            ..Default::default()
+        });
+        meter.process(&[RawAccelerationData::default(); 8]);
+        ::core::assert!(!meter.is_settled());
+        ::core::assert!(meter.velocity_rms().is_none());
+        ::core::assert!(meter.displacement_peak_to_peak().is_none());
+    }
+}
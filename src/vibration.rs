@@ -0,0 +1,215 @@
+use crate::{AccelerationData, RawAccelerationData, SampleFormat};
+
+/// Vibration metrics of a single signal
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct SignalMetrics {
+    /// Mean value in **mg** (before DC removal)
+    pub mean: f32,
+    /// Root mean square in **mg**
+    pub rms: f32,
+    /// Peak (maximum absolute value) in **mg**
+    pub peak: f32,
+    /// Peak-to-peak in **mg**
+    pub peak_to_peak: f32,
+    /// Crest factor (peak / RMS)
+    pub crest_factor: f32,
+    /// Kurtosis (fourth standardized moment, 3.0 for a gaussian signal)
+    pub kurtosis: f32,
+}
+
+/// Vibration metrics of a window of acceleration data
+#[derive(Debug, Copy, Clone, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct VibrationMetrics {
+    /// X-axis metrics
+    pub x: SignalMetrics,
+    /// Y-axis metrics
+    pub y: SignalMetrics,
+    /// Z-axis metrics
+    pub z: SignalMetrics,
+    /// Vector magnitude metrics
+    pub magnitude: SignalMetrics,
+    /// Number of samples in the window
+    pub samples: usize,
+}
+
+/// Compute the vibration metrics of a window of RAW acceleration data
+///
+/// The window is typically one FIFO drain (e.g. 32 samples at 1.6 kHz in [`Mode::HighPerformance`](crate::Mode::HighPerformance)).
+///
+/// # ARGUMENTS
+///
+/// - `samples`: Window of RAW acceleration data
+/// - `format`: Format the samples were taken with (see [`Lis2dtw12::sample_format`](crate::Lis2dtw12::sample_format))
+/// - `remove_dc`: Remove the mean (gravity) of each axis before computing the metrics
+///
+/// # Returns
+///
+/// - The vibration metrics, or `None` if the window is empty
+pub fn analyze(
+    samples: &[RawAccelerationData],
+    format: &SampleFormat,
+    remove_dc: bool,
+) -> Option<VibrationMetrics> {
+    if samples.is_empty() {
+        return None;
+    }
+
+    let mg = |s: &RawAccelerationData| format.to_mg(s);
+    let x = signal_metrics(samples.iter().map(|s| mg(s).x), remove_dc);
+    let y = signal_metrics(samples.iter().map(|s| mg(s).y), remove_dc);
+    let z = signal_metrics(samples.iter().map(|s| mg(s).z), remove_dc);
+
+    // Gravity is removed from the vector before computing the magnitude
+    let (dc_x, dc_y, dc_z) = if remove_dc {
+        (x.mean, y.mean, z.mean)
+    } else {
+        (0.0, 0.0, 0.0)
+    };
+    let magnitude = signal_metrics(
+        samples.iter().map(|s| {
+            let a = mg(s);
+            AccelerationData {
+                x: a.x - dc_x,
+                y: a.y - dc_y,
+                z: a.z - dc_z,
+            }
+            .magnitude()
+        }),
+        false,
+    );
+
+    Some(VibrationMetrics {
+        x,
+        y,
+        z,
+        magnitude,
+        samples: samples.len(),
+    })
+}
+
+/// Compute the metrics of a signal, iterating over the samples twice
+fn signal_metrics<S: Iterator<Item = f32> + Clone>(signal: S, remove_dc: bool) -> SignalMetrics {
+    let (count, sum) = signal
+        .clone()
+        .fold((0usize, 0.0f32), |(n, sum), v| (n + 1, sum + v));
+    let n = count as f32;
+    let mean = sum / n;
+    let offset = if remove_dc { mean } else { 0.0 };
+
+    let mut square_sum = 0.0;
+    let mut m2 = 0.0;
+    let mut m4 = 0.0;
+    let mut peak = 0.0f32;
+    let mut min = f32::MAX;
+    let mut max = f32::MIN;
+    for v in signal {
+        let value = v - offset;
+        let centered = v - mean;
+        square_sum += value * value;
+        m2 += centered * centered;
+        m4 += centered * centered * centered * centered;
+        peak = peak.max(libm::fabsf(value));
+        min = min.min(value);
+        max = max.max(value);
+    }
+
+    let rms = libm::sqrtf(square_sum / n);
+    let variance = m2 / n;
+    SignalMetrics {
+        mean,
+        rms,
+        peak,
+        peak_to_peak: max - min,
+        crest_factor: if rms > 0.0 { peak / rms } else { 0.0 },
+        kurtosis: if variance > 0.0 {
+            m4 / n / (variance * variance)
+        } else {
+            0.0
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::f32::consts::PI;
+
+    fn assert_close(actual: f32, expected: f32) {
+        ::core::assert!(
+            libm::fabsf(actual - expected) < 1e-3 * expected.abs().max(10.0),
+            "{actual} != {expected}"
+        );
+    }
+
+    /// 4 periods of a sine with 8 samples per period, hitting the positive and negative peaks
+    fn sine(amplitude: f32, offset: f32) -> impl Iterator<Item = f32> + Clone {
+        (0..32).map(move |i| offset + amplitude * libm::sinf(2.0 * PI * i as f32 / 8.0))
+    }
+
+    #[test]
+    fn constant_signal() {
+        let metrics = signal_metrics([250.0f32; 32].into_iter(), false);
+        assert_close(metrics.mean, 250.0);
+        assert_close(metrics.rms, 250.0);
+        assert_close(metrics.peak, 250.0);
+        assert_close(metrics.peak_to_peak, 0.0);
+        assert_close(metrics.crest_factor, 1.0);
+        assert_close(metrics.kurtosis, 0.0);
+    }
+
+    #[test]
+    fn constant_signal_dc_removed() {
+        let metrics = signal_metrics([250.0f32; 32].into_iter(), true);
+        assert_close(metrics.mean, 250.0);
+        assert_close(metrics.rms, 0.0);
+        assert_close(metrics.peak, 0.0);
+        assert_close(metrics.crest_factor, 0.0);
+    }
+
+    #[test]
+    fn sine_wave() {
+        let metrics = signal_metrics(sine(100.0, 0.0), false);
+        assert_close(metrics.mean, 0.0);
+        assert_close(metrics.rms, 100.0 / libm::sqrtf(2.0));
+        assert_close(metrics.peak, 100.0);
+        assert_close(metrics.peak_to_peak, 200.0);
+        assert_close(metrics.crest_factor, libm::sqrtf(2.0));
+        assert_close(metrics.kurtosis, 1.5);
+    }
+
+    #[test]
+    fn sine_wave_dc_removed() {
+        let metrics = signal_metrics(sine(100.0, 1000.0), true);
+        assert_close(metrics.mean, 1000.0);
+        assert_close(metrics.rms, 100.0 / libm::sqrtf(2.0));
+        assert_close(metrics.peak, 100.0);
+        assert_close(metrics.crest_factor, libm::sqrtf(2.0));
+    }
+
+    #[test]
+    fn analyze_removes_gravity_from_magnitude() {
+        let samples = [RawAccelerationData {
+            x: 0,
+            y: 0,
+            z: 16384,
+        }; 32];
+        let format = SampleFormat::default();
+        let gravity = format.to_mg(&samples[0]).z;
+
+        let metrics = analyze(&samples, &format, false).unwrap();
+        ::core::assert_eq!(metrics.samples, 32);
+        assert_close(metrics.z.rms, gravity);
+        assert_close(metrics.magnitude.rms, gravity);
+
+        let metrics = analyze(&samples, &format, true).unwrap();
+        assert_close(metrics.z.rms, 0.0);
+        assert_close(metrics.magnitude.peak, 0.0);
+    }
+
+    #[test]
+    fn analyze_empty_window() {
+        ::core::assert!(analyze(&[], &SampleFormat::default(), true).is_none());
+    }
+}
@@ -0,0 +1,96 @@
+//! Pedometer tests on synthetic walking traces
+//!
+//! The traces in `tests/data` are generated signals of an idealized gait, not recordings of a
+//! real walk. They check the step detection and cadence logic, not the accuracy on a real gait.
+
+use lis2dtw12::pedometer::{Pedometer, PedometerConfig};
+use lis2dtw12::{FullScale, Mode, OutputDataRate, RawAccelerationData};
+
+fn load_trace(trace: &str) -> Vec<RawAccelerationData> {
+    trace
+        .lines()
+        .filter(|line| !line.starts_with('#') && !line.starts_with('x'))
+        .map(|line| {
+            let mut values = line.split(',').map(|v| v.trim().parse::<i16>().unwrap());
+            RawAccelerationData {
+                x: values.next().unwrap(),
+                y: values.next().unwrap(),
+                z: values.next().unwrap(),
+            }
+        })
+        .collect()
+}
+
+fn config(odr: OutputDataRate) -> PedometerConfig {
+    PedometerConfig {
+        odr,
+        mode: Mode::ContinuousLowPower1,
+        full_scale: FullScale::G2,
+        ..Default::default()
+    }
+}
+
+/// Feed the trace in FIFO sized batches (32 samples)
+fn run(pedometer: &mut Pedometer, samples: &[RawAccelerationData]) -> u32 {
+    samples
+        .chunks(32)
+        .map(|batch| pedometer.process(batch))
+        .sum()
+}
+
+#[test]
+fn walking_25hz() {
+    let samples = load_trace(include_str!("data/walking_25hz.csv"));
+    let mut pedometer = Pedometer::new(config(OutputDataRate::Hz25)).unwrap();
+
+    let steps = run(&mut pedometer, &samples[..samples.len() - 25]);
+    assert_eq!(steps, pedometer.step_count());
+    assert!((19..=21).contains(&steps), "counted {steps} steps");
+
+    let cadence = pedometer.cadence().unwrap();
+    assert!((98.0..=118.0).contains(&cadence), "cadence {cadence}");
+}
+
+#[test]
+fn walking_50hz() {
+    let samples = load_trace(include_str!("data/walking_50hz.csv"));
+    let mut pedometer = Pedometer::new(config(OutputDataRate::Hz50)).unwrap();
+
+    let steps = run(&mut pedometer, &samples[..samples.len() - 50]);
+    assert!((29..=31).contains(&steps), "counted {steps} steps");
+
+    let cadence = pedometer.cadence().unwrap();
+    assert!((122.0..=142.0).contains(&cadence), "cadence {cadence}");
+}
+
+#[test]
+fn cadence_resets_after_walking() {
+    let samples = load_trace(include_str!("data/walking_25hz.csv"));
+    let mut pedometer = Pedometer::new(config(OutputDataRate::Hz25)).unwrap();
+
+    run(&mut pedometer, &samples);
+    // Standing still for a while after the trace
+    let still = samples[0];
+    for _ in 0..50 {
+        assert!(!pedometer.process_sample(&still));
+    }
+    assert_eq!(pedometer.cadence(), None);
+
+    pedometer.reset();
+    assert_eq!(pedometer.step_count(), 0);
+}
+
+#[test]
+fn standing_still() {
+    let samples = load_trace(include_str!("data/walking_25hz.csv"));
+    let mut pedometer = Pedometer::new(config(OutputDataRate::Hz25)).unwrap();
+
+    // The first two seconds of the trace are standing still
+    assert_eq!(run(&mut pedometer, &samples[..50]), 0);
+    assert_eq!(pedometer.cadence(), None);
+}
+
+#[test]
+fn power_down_rejected() {
+    assert!(Pedometer::new(config(OutputDataRate::PowerDown)).is_none());
+}